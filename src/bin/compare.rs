@@ -1,8 +1,75 @@
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
+use std::fs::File;
+use std::io::BufReader;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+#[path = "../score.rs"]
+mod score;
+
+use score::{ScoreWeights, leaderboard, overall_score, parse_score_weights};
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Maximum allowed growth of the average runtime, in percent
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Restore the original src layout after an interrupted comparison, then exit
+    #[arg(long)]
+    recover: bool,
+
+    /// Compare the current sources against baseline_results.json as committed at this git ref
+    #[arg(long, value_name = "REF", conflicts_with = "recover")]
+    git_baseline: Option<String>,
+
+    /// Exponents of the success rate and the speed in the score, e.g. `sr=0.7,time=0.3`
+    #[arg(long, value_parser = parse_score_weights, default_value = "sr=1,time=1")]
+    score_weights: ScoreWeights,
+}
+
+/// Records which rename of the directory swap completed last, so that an
+/// interrupted comparison can be undone with `--recover`.
+const LOCK_FILE: &str = "src-swap.lock.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+enum SwapStep {
+    /// The swap is about to start; nothing has been renamed yet
+    Started,
+    /// src was moved to src-original-temp
+    OriginalMoved,
+    /// src-new was moved to src
+    NewInPlace,
+    /// While restoring, src was moved back to src-new
+    NewMovedBack,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SwapLock {
+    step: SwapStep,
+}
+
+// Subset of the stats written by the main benchmark binary
+#[derive(Deserialize)]
+struct StatPoint {
+    dim: usize,
+    success_rate: f64,
+    avg_runtime_sec: f64,
+    // Missing from older results and from runs with --parallel-functions
+    #[serde(default)]
+    avg_peak_memory_bytes: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct AllStats {
+    data: BTreeMap<String, Vec<StatPoint>>,
+}
+
 struct DirectoryGuard {
     root: PathBuf,
     swapped: bool,
@@ -22,23 +89,36 @@ impl DirectoryGuard {
             return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "src-new not found"));
         }
 
-        if src_temp.exists() {
+        if src_temp.exists() || self.root.join(LOCK_FILE).exists() {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::AlreadyExists,
-                "src-original-temp already exists. Previous run might have failed.",
+                "src-original-temp or a swap lockfile already exists. Previous run might have \
+                 failed; run with --recover to restore the original layout.",
             ));
         }
 
+        self.record(SwapStep::Started)?;
         println!("Renaming {:?} -> {:?}", src, src_temp);
-        fs::rename(&src, &src_temp)?;
+        if let Err(e) = fs::rename(&src, &src_temp) {
+            // Nothing was moved, so the lockfile would only block the next run
+            let _ = fs::remove_file(self.root.join(LOCK_FILE));
+            return Err(e);
+        }
+        self.swapped = true;
+        self.record(SwapStep::OriginalMoved)?;
 
         println!("Renaming {:?} -> {:?}", src_new, src);
         fs::rename(&src_new, &src)?;
+        self.record(SwapStep::NewInPlace)?;
 
-        self.swapped = true;
         Ok(())
     }
 
+    fn record(&self, step: SwapStep) -> std::io::Result<()> {
+        let lock = serde_json::to_string(&SwapLock { step })?;
+        fs::write(self.root.join(LOCK_FILE), lock)
+    }
+
     fn restore(&mut self) -> std::io::Result<()> {
         if !self.swapped {
             return Ok(());
@@ -52,11 +132,13 @@ impl DirectoryGuard {
         if src.exists() {
             println!("Renaming {:?} -> {:?}", src, src_new);
             fs::rename(&src, &src_new)?;
+            self.record(SwapStep::NewMovedBack)?;
         }
         if src_temp.exists() {
             println!("Renaming {:?} -> {:?}", src_temp, src);
             fs::rename(&src_temp, &src)?;
         }
+        fs::remove_file(self.root.join(LOCK_FILE))?;
         self.swapped = false;
         Ok(())
     }
@@ -73,10 +155,72 @@ impl Drop for DirectoryGuard {
     }
 }
 
+/// Undoes an interrupted swap recorded in the lockfile. The process can be
+/// killed between a rename and the lockfile update, so every remaining step
+/// checks the directories instead of trusting the recorded step alone.
+fn recover(root: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let lock_path = root.join(LOCK_FILE);
+    if !lock_path.exists() {
+        println!("No swap lockfile at {:?}; nothing to recover.", lock_path);
+        return Ok(());
+    }
+    let lock: SwapLock = serde_json::from_str(&fs::read_to_string(&lock_path)?)?;
+    println!("Recovering from interrupted swap (last completed step: {:?})", lock.step);
+
+    let src = root.join("src");
+    let src_new = root.join("src-new");
+    let src_temp = root.join("src-original-temp");
+
+    // While the original sources are parked in src-original-temp, src can only hold src-new
+    if src_temp.exists() {
+        if src.exists() {
+            if src_new.exists() {
+                return Err(format!(
+                    "{:?}, {:?} and {:?} all exist; resolve the layout manually",
+                    src, src_new, src_temp
+                )
+                .into());
+            }
+            println!("Renaming {:?} -> {:?}", src, src_new);
+            fs::rename(&src, &src_new)?;
+        }
+        println!("Renaming {:?} -> {:?}", src_temp, src);
+        fs::rename(&src_temp, &src)?;
+    }
+
+    fs::remove_file(&lock_path)?;
+    println!("Recovery complete.");
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
     let current_dir = env::current_dir()?;
     let root_dir = current_dir.parent().ok_or("Cannot find parent dir")?.to_path_buf();
 
+    if cli.recover {
+        return recover(&root_dir);
+    }
+
+    if let Some(git_ref) = &cli.git_baseline {
+        let baseline_path = git_baseline(git_ref)?;
+        println!("Loaded baseline_results.json from {} into {:?}", git_ref, baseline_path);
+        let baseline_arg = baseline_path.to_str().ok_or("Non-UTF-8 temporary path")?;
+        if Path::new("current_results.json").exists() {
+            fs::remove_file("current_results.json")?;
+        }
+        println!("\n- Running benchmarks on the current source");
+        run_bench(&[
+            "--release",
+            "--",
+            "--load-baseline",
+            baseline_arg,
+            "--save-json",
+            "current_results.json",
+        ])?;
+        return report(baseline_arg, "current_results.json", cli.threshold, &cli.score_weights);
+    }
+
     let src_new = root_dir.join("src-new");
     if !src_new.exists() {
         println!("'src-new' folder not found at {:?}. Running standard benchmarks only.", src_new);
@@ -86,8 +230,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("Found 'src-new'. Starting Comparison Benchmark Suite.");
 
-    if Path::new("baseline_results.json").exists() {
-        fs::remove_file("baseline_results.json")?;
+    for stale in ["baseline_results.json", "current_results.json"] {
+        if Path::new(stale).exists() {
+            fs::remove_file(stale)?;
+        }
     }
 
     println!("\n- Phase 1: Baseline (Original Source)");
@@ -111,16 +257,221 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         println!("\n- Phase 2: Comparison (New Source)");
-        run_bench(&["--release", "--", "--load-baseline", "baseline_results.json"])?;
+        run_bench(&[
+            "--release",
+            "--",
+            "--load-baseline",
+            "baseline_results.json",
+            "--save-json",
+            "current_results.json",
+        ])?;
 
         println!("\n- Restoring directory structure");
         // guard dropped here automatically restores
     }
 
     println!("Comparison complete.");
+    report("baseline_results.json", "current_results.json", cli.threshold, &cli.score_weights)
+}
+
+/// Prints the speedup summary and exits with status 1 if any regression is found.
+fn report(
+    baseline_path: &str,
+    current_path: &str,
+    threshold: f64,
+    weights: &ScoreWeights,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let baseline = load_stats(baseline_path)?;
+    let current = load_stats(current_path)?;
+    print_speedup_summary(&baseline, &current);
+    print_score_comparison(&baseline, &current, weights);
+    let regressions = find_regressions(&baseline, &current, threshold);
+    if !regressions.is_empty() {
+        println!("\nDetected {} regression(s):", regressions.len());
+        for regression in &regressions {
+            println!("  {}", regression);
+        }
+        std::process::exit(1);
+    }
+    println!("No regressions detected (runtime threshold: {}%).", threshold);
     Ok(())
 }
 
+/// Writes baseline_results.json as committed at `git_ref` to a temporary file
+/// and returns its path.
+fn git_baseline(git_ref: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    // The `./` prefix resolves the path against the current directory rather than the repo root
+    let output = Command::new("git")
+        .args(["show", &format!("{}:./baseline_results.json", git_ref)])
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("does not exist") || stderr.contains("exists on disk, but not in") {
+            return Err(format!("baseline_results.json does not exist at {}", git_ref).into());
+        }
+        return Err(format!("git show failed for {}: {}", git_ref, stderr.trim()).into());
+    }
+
+    // Refs may contain slashes, which can't appear in a file name
+    let file_name = format!("git-baseline-{}.json", git_ref.replace(['/', '\\', ':'], "_"));
+    let path = env::temp_dir().join(file_name);
+    fs::write(&path, &output.stdout)?;
+    Ok(path)
+}
+
+fn load_stats(path: &str) -> Result<AllStats, Box<dyn std::error::Error>> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// Prints the speedup (baseline runtime / new runtime), success rate change and
+/// peak memory change of every (function, dim) present in both result sets, plus
+/// the geometric-mean speedup.
+fn print_speedup_summary(baseline: &AllStats, current: &AllStats) {
+    let func_names: Vec<&String> = current.data.keys().collect();
+
+    println!("\n{:<24} {:>5} {:>9} {:>7} {:>8}", "Function", "Dim", "Speedup", "Δ SR", "Δ Mem");
+    let mut speedups = Vec::new();
+    for func_name in func_names {
+        let Some(base_points) = baseline.data.get(func_name) else {
+            continue;
+        };
+        for cur in &current.data[func_name] {
+            let Some(base) = base_points.iter().find(|b| b.dim == cur.dim) else {
+                continue;
+            };
+            let speedup = base.avg_runtime_sec / cur.avg_runtime_sec;
+            let memory_change = match (base.avg_peak_memory_bytes, cur.avg_peak_memory_bytes) {
+                (Some(base_mem), Some(cur_mem)) => {
+                    format!("{:+.1}%", (cur_mem / base_mem - 1.0) * 100.0)
+                }
+                _ => "n/a".to_string(),
+            };
+            println!(
+                "{:<24} {:>5} {:>8.3}x {:>+7.2} {:>8}",
+                func_name,
+                cur.dim,
+                speedup,
+                cur.success_rate - base.success_rate,
+                memory_change
+            );
+            // Zero runtimes (e.g. every run failed) have no meaningful ratio
+            if speedup.is_finite() && speedup > 0.0 {
+                speedups.push(speedup);
+            }
+        }
+    }
+    match geomean(&speedups) {
+        Some(g) => println!("Geometric-mean speedup: {:.3}x over {} cases", g, speedups.len()),
+        None => println!("Geometric-mean speedup: no comparable cases"),
+    }
+}
+
+/// Prints the functions ranked by their current score next to their baseline score.
+/// Both scores cover only the dimensions present in both result sets.
+fn print_score_comparison(baseline: &AllStats, current: &AllStats, weights: &ScoreWeights) {
+    let mut base_scores = Vec::new();
+    let mut cur_scores = Vec::new();
+    for (func_name, current_points) in &current.data {
+        let Some(base_points) = baseline.data.get(func_name) else {
+            continue;
+        };
+        let (base, cur): (Vec<f64>, Vec<f64>) = current_points
+            .iter()
+            .filter_map(|cur| {
+                let base = base_points.iter().find(|b| b.dim == cur.dim)?;
+                Some((
+                    weights.score(base.success_rate, base.avg_runtime_sec),
+                    weights.score(cur.success_rate, cur.avg_runtime_sec),
+                ))
+            })
+            .unzip();
+        base_scores.push((func_name.clone(), base));
+        cur_scores.push((func_name.clone(), cur));
+    }
+    let base_board = leaderboard(base_scores);
+    let cur_board = leaderboard(cur_scores);
+    if cur_board.is_empty() {
+        return;
+    }
+
+    println!(
+        "\nLeaderboard (score = SR^{} / runtime^{}):\n{:<4} {:<24} {:>10} {:>10} {:>10}",
+        weights.sr, weights.time, "Rank", "Function", "Baseline", "Current", "Change"
+    );
+    for (rank, (func_name, cur)) in cur_board.iter().enumerate() {
+        let base = base_board.iter().find(|(name, _)| name == func_name).map_or(0.0, |b| b.1);
+        println!(
+            "{:<4} {:<24} {:>10.4} {:>10.4} {:>10}",
+            rank + 1,
+            func_name,
+            base,
+            cur,
+            score_change(base, *cur)
+        );
+    }
+    match (overall_score(&base_board), overall_score(&cur_board)) {
+        (Some((base, _)), Some((cur, n))) => println!(
+            "Overall score over {} functions: {:.4} -> {:.4} ({})",
+            n,
+            base,
+            cur,
+            score_change(base, cur)
+        ),
+        _ => println!("Overall score: no function succeeded in both result sets"),
+    }
+}
+
+fn score_change(base: f64, cur: f64) -> &'static str {
+    if cur > base {
+        "improved"
+    } else if cur < base {
+        "regressed"
+    } else {
+        "unchanged"
+    }
+}
+
+/// Geometric mean of positive values, `None` if there are none.
+fn geomean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some((values.iter().map(|v| v.ln()).sum::<f64>() / values.len() as f64).exp())
+}
+
+/// Lists every (function, dim) whose average runtime grew by more than
+/// `threshold_pct` percent or whose success rate dropped.
+fn find_regressions(baseline: &AllStats, current: &AllStats, threshold_pct: f64) -> Vec<String> {
+    let mut regressions = Vec::new();
+    for (func_name, current_points) in &current.data {
+        let Some(base_points) = baseline.data.get(func_name) else {
+            continue;
+        };
+        for cur in current_points {
+            let Some(base) = base_points.iter().find(|b| b.dim == cur.dim) else {
+                continue;
+            };
+            let growth_pct = (cur.avg_runtime_sec / base.avg_runtime_sec - 1.0) * 100.0;
+            if growth_pct > threshold_pct {
+                regressions.push(format!(
+                    "{} (dim {}): runtime {:.4}s -> {:.4}s (+{:.1}%)",
+                    func_name, cur.dim, base.avg_runtime_sec, cur.avg_runtime_sec, growth_pct
+                ));
+            }
+            if cur.success_rate < base.success_rate {
+                regressions.push(format!(
+                    "{} (dim {}): success rate {:.2} -> {:.2}",
+                    func_name, cur.dim, base.success_rate, cur.success_rate
+                ));
+            }
+        }
+    }
+    regressions.sort();
+    regressions
+}
+
 fn run_bench(args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
     // We want to run the MAIN binary of this package.
     // Since we are currently running 'compare' binary which is inside the same package,
@@ -136,3 +487,145 @@ fn run_bench(args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Stats of `(function, dim, success_rate, avg_runtime_sec)` points.
+    fn stats(points: &[(&str, usize, f64, f64)]) -> AllStats {
+        let mut data: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
+        for &(name, dim, success_rate, avg_runtime_sec) in points {
+            data.entry(name.to_string()).or_default().push(json!({
+                "dim": dim,
+                "success_rate": success_rate,
+                "avg_runtime_sec": avg_runtime_sec,
+            }));
+        }
+        serde_json::from_value(json!({ "data": data })).unwrap()
+    }
+
+    #[test]
+    fn find_regressions_flags_slowdowns_and_success_drops() {
+        let baseline = stats(&[("Ackley", 2, 1.0, 1.0), ("Ackley", 10, 0.5, 2.0)]);
+        let current = stats(&[
+            ("Ackley", 2, 0.9, 1.05),
+            ("Ackley", 10, 0.5, 3.0),
+            ("Ackley", 50, 0.0, 9.0),
+            ("Sphere", 10, 0.0, 9.0),
+        ]);
+        assert_eq!(
+            find_regressions(&baseline, &current, 10.0),
+            vec![
+                "Ackley (dim 10): runtime 2.0000s -> 3.0000s (+50.0%)",
+                "Ackley (dim 2): success rate 1.00 -> 0.90",
+            ]
+        );
+        // A 5% slowdown only counts under a lower threshold
+        assert_eq!(find_regressions(&baseline, &current, 1.0).len(), 3);
+        assert!(find_regressions(&baseline, &baseline, 0.0).is_empty());
+    }
+
+    #[test]
+    fn geomean_of_known_values() {
+        assert!((geomean(&[2.0, 8.0]).unwrap() - 4.0).abs() < 1e-12);
+        assert!((geomean(&[1.0, 10.0, 100.0]).unwrap() - 10.0).abs() < 1e-12);
+        assert_eq!(geomean(&[3.5]), Some(3.5));
+    }
+
+    #[test]
+    fn geomean_of_empty_or_zero_input() {
+        assert_eq!(geomean(&[]), None);
+        // A zero drags the mean of the logarithms to -inf
+        assert_eq!(geomean(&[0.0, 4.0]), Some(0.0));
+    }
+
+    /// Temporary root holding the given `(directory, sources)` pairs, each directory
+    /// marked by a file named after the sources it holds, and a lockfile at `step`.
+    fn swap_layout(name: &str, step: SwapStep, dirs: &[(&str, &str)]) -> PathBuf {
+        let root = env::temp_dir().join(format!("compare-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (dir, sources) in dirs {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            fs::write(root.join(dir).join(sources), "").unwrap();
+        }
+        fs::write(root.join(LOCK_FILE), serde_json::to_string(&SwapLock { step }).unwrap())
+            .unwrap();
+        root
+    }
+
+    /// Checks that `recover` restored the original layout, then removes the root.
+    fn assert_restored(root: &Path) {
+        assert!(root.join("src").join("original").exists());
+        assert!(root.join("src-new").join("new").exists());
+        assert!(!root.join("src-original-temp").exists());
+        assert!(!root.join(LOCK_FILE).exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn recover_after_started() {
+        let root =
+            swap_layout("started", SwapStep::Started, &[("src", "original"), ("src-new", "new")]);
+        recover(&root).unwrap();
+        assert_restored(&root);
+    }
+
+    #[test]
+    fn recover_after_original_moved() {
+        let root = swap_layout(
+            "original-moved",
+            SwapStep::OriginalMoved,
+            &[("src-original-temp", "original"), ("src-new", "new")],
+        );
+        recover(&root).unwrap();
+        assert_restored(&root);
+    }
+
+    #[test]
+    fn recover_after_new_in_place() {
+        let root = swap_layout(
+            "new-in-place",
+            SwapStep::NewInPlace,
+            &[("src-original-temp", "original"), ("src", "new")],
+        );
+        recover(&root).unwrap();
+        assert_restored(&root);
+    }
+
+    #[test]
+    fn recover_after_new_moved_back() {
+        let root = swap_layout(
+            "new-moved-back",
+            SwapStep::NewMovedBack,
+            &[("src-original-temp", "original"), ("src-new", "new")],
+        );
+        recover(&root).unwrap();
+        assert_restored(&root);
+    }
+
+    #[test]
+    fn recover_trusts_the_directories_over_a_stale_step() {
+        // Killed after moving src-new into place but before recording it
+        let root = swap_layout(
+            "stale-step",
+            SwapStep::OriginalMoved,
+            &[("src-original-temp", "original"), ("src", "new")],
+        );
+        recover(&root).unwrap();
+        assert_restored(&root);
+    }
+
+    #[test]
+    fn recover_refuses_an_ambiguous_layout() {
+        let root = swap_layout(
+            "ambiguous",
+            SwapStep::NewInPlace,
+            &[("src-original-temp", "original"), ("src", "new"), ("src-new", "new")],
+        );
+        assert!(recover(&root).is_err());
+        assert!(root.join(LOCK_FILE).exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
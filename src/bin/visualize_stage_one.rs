@@ -1,17 +1,74 @@
 use argmin_testfunctions::{ackley, cross_in_tray, levy, rastrigin, rosenbrock};
+use clap::Parser;
+use clap::builder::RangedU64ValueParser;
 use globalsearch::problem::Problem;
 use globalsearch::scatter_search::ScatterSearch;
 use globalsearch::types::{EvaluationError, OQNLPParams};
 use ndarray::{Array1, Array2};
-use plotly::common::{Marker, Mode, Title};
-use plotly::{Contour, Layout, Plot, Scatter};
+use plotly::common::color::NamedColor;
+use plotly::common::{Marker, MarkerSymbol, Mode, Title};
+use plotly::{Contour, Layout, Plot, Scatter, Scatter3D, Surface};
+use serde_json::{Value, json};
 use std::error::Error;
 
+#[path = "../objectives.rs"]
+mod objectives;
+#[path = "../plot_output.rs"]
+mod plot_output;
+
+use objectives::six_hump_camel;
+use plot_output::{PlotFormat, write_plot};
+
+/// Cells per axis of the grid used to measure reference set coverage
+const COVERAGE_CELLS: usize = 10;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Problem to visualize (all if not specified)
+    #[arg(short, long)]
+    function: Option<String>,
+
+    /// Grid points per axis for the objective landscape
+    #[arg(long, default_value_t = 80, value_parser = RangedU64ValueParser::<usize>::new().range(2..))]
+    resolution: usize,
+
+    /// Number of independent Stage 1 runs (one subplot each)
+    #[arg(short, long, default_value_t = 6, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    runs: usize,
+
+    /// Output format of the generated plots (png/svg require the `kaleido` feature)
+    #[arg(long, value_enum, default_value_t = PlotFormat::Html)]
+    plot_format: PlotFormat,
+
+    /// Stage-one population size
+    #[arg(long, default_value_t = 20)]
+    population_size: usize,
+
+    /// OQNLP distance factor (library default if not specified)
+    #[arg(long)]
+    distance_factor: Option<f64>,
+
+    /// Render the objective as a 3D surface with the populations overlaid instead of contour subplots
+    #[arg(long)]
+    surface: bool,
+
+    /// Also animate how the reference set of the first run evolves (`plots/<fn>_evolution.html`)
+    #[arg(long)]
+    animate: bool,
+
+    /// Number of animation frames, each allowing one more Stage 1 iteration
+    #[arg(long, default_value_t = 10, requires = "animate")]
+    animation_frames: usize,
+}
+
 #[derive(Clone)]
 struct VisualProblem {
     name: String,
     obj_fn: fn(&[f64]) -> f64,
     bounds: [[f64; 2]; 2],
+    // Known global minimizer of `obj_fn`, one of them where there are several
+    optimum: [f64; 2],
 }
 
 impl Problem for VisualProblem {
@@ -28,22 +85,171 @@ impl Problem for VisualProblem {
     }
 }
 
+/// Mean pairwise Euclidean distance between reference set points, normalized
+/// by the diagonal of the bounds so values are comparable across functions.
+fn reference_set_diversity(ref_set: &[(Array1<f64>, f64)], bounds: &[[f64; 2]; 2]) -> f64 {
+    let n = ref_set.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let diagonal = bounds.iter().map(|b| (b[1] - b[0]).powi(2)).sum::<f64>().sqrt();
+    let mut total = 0.0;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            total += (&ref_set[i].0 - &ref_set[j].0).mapv(|d| d * d).sum().sqrt();
+        }
+    }
+    let pairs = (n * (n - 1) / 2) as f64;
+    total / pairs / diagonal
+}
+
+/// Fraction of the cells of a `cells` x `cells` grid over the bounds that
+/// contain at least one reference set point.
+fn reference_set_coverage(
+    ref_set: &[(Array1<f64>, f64)],
+    bounds: &[[f64; 2]; 2],
+    cells: usize,
+) -> f64 {
+    let cell_of = |x: f64, b: [f64; 2]| {
+        // Points on the upper bound belong to the last cell
+        (((x - b[0]) / (b[1] - b[0]) * cells as f64) as usize).min(cells - 1)
+    };
+    let mut occupied = vec![false; cells * cells];
+    for (p, _) in ref_set {
+        occupied[cell_of(p[1], bounds[1]) * cells + cell_of(p[0], bounds[0])] = true;
+    }
+    occupied.iter().filter(|&&o| o).count() as f64 / (cells * cells) as f64
+}
+
+/// Stage 1 parameters for the run with the given seed.
+fn stage_one_params(cli: &Cli, seed: u64) -> OQNLPParams {
+    let defaults = OQNLPParams::default();
+    OQNLPParams {
+        seed,
+        population_size: cli.population_size,
+        distance_factor: cli.distance_factor.unwrap_or(defaults.distance_factor),
+        ..defaults
+    }
+}
+
+/// Writes `plots/<fn>_evolution.html`, stepping through the reference set of the first
+/// run as Stage 1 progresses. `ScatterSearch` only returns the final reference set, so
+/// frame `k` approximates the state after `k` iterations by rerunning Stage 1 with the
+/// same seed and `iterations` capped at `k`.
+fn write_evolution(
+    prob: &VisualProblem,
+    cli: &Cli,
+    x_space: &[f64],
+    y_space: &[f64],
+    z: &[Vec<f64>],
+) -> Result<(), Box<dyn Error>> {
+    // OQNLP rejects more iterations than the population size
+    let num_frames = cli.animation_frames.clamp(1, cli.population_size);
+    let mut frames = Vec::new();
+    for k in 1..=num_frames {
+        let params = OQNLPParams { iterations: k, ..stage_one_params(cli, 0) };
+        let (ref_set, _) = ScatterSearch::new(prob.clone(), params)?.run()?;
+        let px: Vec<f64> = ref_set.iter().map(|(p, _)| p[0]).collect();
+        let py: Vec<f64> = ref_set.iter().map(|(p, _)| p[1]).collect();
+        frames.push((k, px, py));
+    }
+
+    let mut plot = Plot::new();
+    plot.add_trace(
+        Contour::new(x_space.to_vec(), y_space.to_vec(), z.to_vec())
+            .show_scale(false)
+            .show_legend(false),
+    );
+    let (_, px, py) = &frames[0];
+    plot.add_trace(
+        Scatter::new(px.clone(), py.clone())
+            .name("Reference Set")
+            .mode(Mode::Markers)
+            .marker(Marker::new().size(6).color(NamedColor::Red)),
+    );
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} - Stage 1 Reference Set Evolution", prob.name)))
+            .width(900)
+            .height(800),
+    );
+
+    // plotly.rs has no animation support, so the frames and controls are added to its JSON
+    let mut figure: Value = serde_json::from_str(&plot.to_json())?;
+    figure["frames"] = frames
+        .iter()
+        .map(|(k, px, py)| {
+            json!({ "name": k.to_string(), "data": [{ "x": px, "y": py }], "traces": [1] })
+        })
+        .collect();
+    let step = |k: &usize| {
+        json!({
+            "label": k.to_string(),
+            "method": "animate",
+            "args": [[k.to_string()], { "mode": "immediate", "frame": { "duration": 0 } }],
+        })
+    };
+    figure["layout"]["updatemenus"] = json!([{
+        "type": "buttons",
+        "showactive": false,
+        "x": 0.0,
+        "y": 0.0,
+        "xanchor": "right",
+        "yanchor": "top",
+        "pad": { "t": 60, "r": 10 },
+        "buttons": [
+            {
+                "label": "Play",
+                "method": "animate",
+                "args": [null, { "fromcurrent": true, "frame": { "duration": 600 } }],
+            },
+            {
+                "label": "Pause",
+                "method": "animate",
+                "args": [[null], { "mode": "immediate", "frame": { "duration": 0 } }],
+            },
+        ],
+    }]);
+    figure["layout"]["sliders"] = json!([{
+        "currentvalue": { "prefix": "Iterations: " },
+        "pad": { "t": 50 },
+        "steps": frames.iter().map(|(k, _, _)| step(k)).collect::<Vec<_>>(),
+    }]);
+
+    let html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <script src=\"https://cdn.plot.ly/plotly-2.35.2.min.js\"></script>\n</head>\n<body>\n\
+         <div id=\"plot\"></div>\n<script>\nPlotly.newPlot(\"plot\", {});\n</script>\n</body>\n</html>\n",
+        figure
+    );
+    let _ = std::fs::create_dir_all("plots");
+    let path = format!("plots/{}_evolution.html", prob.name.to_lowercase());
+    std::fs::write(&path, html)?;
+    println!("  Saved {}-frame animation to {}", num_frames, path);
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+
     let problems = vec![
         VisualProblem {
             name: "Rosenbrock".to_string(),
             obj_fn: |x| rosenbrock(x),
             bounds: [[-2.0, 2.0], [-1.0, 3.0]],
+            optimum: [1.0, 1.0],
         },
         VisualProblem {
             name: "Rastrigin".to_string(),
             obj_fn: |x| rastrigin(x),
             bounds: [[-5.12 + 1.0, 5.12 + 1.0], [-5.12 + 1.0, 5.12 + 1.0]],
+            optimum: [0.0, 0.0],
         },
         VisualProblem {
             name: "Ackley".to_string(),
             obj_fn: |x| ackley(x),
             bounds: [[-5.0 + 1.0, 5.0 + 1.0], [-5.0 + 1.0, 5.0 + 1.0]],
+            optimum: [0.0, 0.0],
         },
         VisualProblem {
             name: "Griewank".to_string(),
@@ -57,34 +263,39 @@ fn main() -> Result<(), Box<dyn Error>> {
                 sum - prod + 1.0
             },
             bounds: [[-600.0 + 1.0, 600.0 + 1.0], [-600.0 + 1.0, 600.0 + 1.0]],
+            optimum: [0.0, 0.0],
         },
         VisualProblem {
             name: "Levy".to_string(),
             obj_fn: |x| levy(x),
             bounds: [[-10.0, 10.0], [-10.0, 10.0]],
+            optimum: [1.0, 1.0],
         },
         VisualProblem {
             name: "SixHumpCamel".to_string(),
-            obj_fn: |x| {
-                let x1 = x[0];
-                let x2 = x[1];
-                (4.0 - 2.1 * x1.powi(2) + x1.powi(4) / 3.0) * x1.powi(2)
-                    + x1 * x2
-                    + (-4.0 + 4.0 * x2.powi(2)) * x2.powi(2)
-            },
+            obj_fn: six_hump_camel,
             bounds: [[-3.0, 3.0], [-2.0, 2.0]],
+            optimum: [0.0898, -0.7126],
         },
         VisualProblem {
             name: "CrossInTray".to_string(),
             obj_fn: |x| cross_in_tray(&[x[0], x[1]]),
             bounds: [[-10.0, 10.0], [-10.0, 10.0]],
+            optimum: [1.34941, 1.34941],
         },
     ];
 
+    let problems: Vec<VisualProblem> = match &cli.function {
+        Some(name) => {
+            problems.into_iter().filter(|p| p.name.to_lowercase() == name.to_lowercase()).collect()
+        }
+        None => problems,
+    };
+
     for prob in problems {
         println!("Visualizing Stage 1 population for: {}", prob.name);
 
-        let res = 80;
+        let res = cli.resolution;
         let b = prob.bounds;
         let x_space: Vec<f64> = (0..res)
             .map(|i| b[0][0] + (b[0][1] - b[0][0]) * (i as f64 / (res - 1) as f64))
@@ -103,23 +314,49 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
 
         let mut plot = Plot::new();
-        let num_runs = 6;
-        let rows = 2;
-        let cols = 3;
+        let num_runs = cli.runs;
+        // Roughly square grid with enough cells for every run
+        let cols = (num_runs as f64).sqrt().ceil().max(1.0) as usize;
+        let rows = num_runs.div_ceil(cols).max(1);
+
+        if cli.surface {
+            plot.add_trace(
+                Surface::new(z.clone())
+                    .x(x_space.clone())
+                    .y(y_space.clone())
+                    .show_scale(false)
+                    .name(&prob.name),
+            );
+        }
 
         for run in 0..num_runs {
-            let params = OQNLPParams {
-                seed: run as u64 * 82731,
-                population_size: 20,
-                ..OQNLPParams::default()
-            };
+            let params = stage_one_params(&cli, run as u64 * 82731);
 
             let ss = ScatterSearch::new(prob.clone(), params)?;
             let (ref_set, _) = ss.run()?;
+            println!(
+                "  Run {}: diversity = {:.3}, coverage = {:.2}",
+                run + 1,
+                reference_set_diversity(&ref_set, &prob.bounds),
+                reference_set_coverage(&ref_set, &prob.bounds, COVERAGE_CELLS)
+            );
 
             let px: Vec<f64> = ref_set.iter().map(|(p, _)| p[0]).collect();
             let py: Vec<f64> = ref_set.iter().map(|(p, _)| p[1]).collect();
 
+            if cli.surface {
+                // Place each point on the landscape at its actual objective value
+                let pz: Vec<f64> =
+                    ref_set.iter().map(|(p, _)| (prob.obj_fn)(&[p[0], p[1]])).collect();
+                plot.add_trace(
+                    Scatter3D::new(px, py, pz)
+                        .name(format!("Run {}", run + 1))
+                        .mode(Mode::Markers)
+                        .marker(Marker::new().size(3)),
+                );
+                continue;
+            }
+
             let trace_index = run + 1;
             let x_axis =
                 if trace_index == 1 { "x".to_string() } else { format!("x{}", trace_index) };
@@ -136,34 +373,107 @@ fn main() -> Result<(), Box<dyn Error>> {
             let scatter = Scatter::new(px, py)
                 .name(format!("Run {}", run + 1))
                 .mode(Mode::Markers)
-                .marker(Marker::new().size(5).color(plotly::common::color::NamedColor::Red))
+                .marker(Marker::new().size(5).color(NamedColor::Red))
                 .x_axis(&x_axis)
                 .y_axis(&y_axis);
             plot.add_trace(scatter);
+
+            // Every subplot gets the same markers, so only the first one adds legend entries
+            if let Some((best, _)) = ref_set.iter().min_by(|a, b| a.1.total_cmp(&b.1)) {
+                plot.add_trace(
+                    Scatter::new(vec![best[0]], vec![best[1]])
+                        .name("Best Reference Point")
+                        .legend_group("best")
+                        .show_legend(run == 0)
+                        .mode(Mode::Markers)
+                        .marker(
+                            Marker::new()
+                                .size(11)
+                                .symbol(MarkerSymbol::Diamond)
+                                .color(NamedColor::LimeGreen),
+                        )
+                        .x_axis(&x_axis)
+                        .y_axis(&y_axis),
+                );
+            }
+            plot.add_trace(
+                Scatter::new(vec![prob.optimum[0]], vec![prob.optimum[1]])
+                    .name("Global Optimum")
+                    .legend_group("optimum")
+                    .show_legend(run == 0)
+                    .mode(Mode::Markers)
+                    .marker(
+                        Marker::new().size(14).symbol(MarkerSymbol::Star).color(NamedColor::Gold),
+                    )
+                    .x_axis(&x_axis)
+                    .y_axis(&y_axis),
+            );
         }
 
-        let layout = Layout::new()
-            .title(Title::with_text(format!(
-                "{} - Stage 1 Population (6 Stochastic Runs)",
-                prob.name
-            )))
-            .grid(
-                plotly::layout::LayoutGrid::new()
-                    .rows(rows)
-                    .columns(cols)
-                    .pattern(plotly::layout::GridPattern::Independent),
-            )
-            .width(1200)
-            .height(800);
+        let (layout, stem) = if cli.surface {
+            let layout = Layout::new()
+                .title(Title::with_text(format!(
+                    "{} - Stage 1 Population Surface ({} Stochastic Runs)",
+                    prob.name, num_runs
+                )))
+                .width(1200)
+                .height(900);
+            (layout, format!("{}_population_surface", prob.name.to_lowercase()))
+        } else {
+            let layout = Layout::new()
+                .title(Title::with_text(format!(
+                    "{} - Stage 1 Population ({} Stochastic Runs)",
+                    prob.name, num_runs
+                )))
+                .grid(
+                    plotly::layout::LayoutGrid::new()
+                        .rows(rows)
+                        .columns(cols)
+                        .pattern(plotly::layout::GridPattern::Independent),
+                )
+                .width(1200)
+                .height(400 * rows);
+            (layout, format!("{}_population", prob.name.to_lowercase()))
+        };
 
         plot.set_layout(layout);
 
-        let _ = std::fs::create_dir_all("plots");
+        write_plot(&plot, &stem, cli.plot_format);
+        println!("  Saved plot to plots/{}", stem);
 
-        let filename = format!("plots/{}_population.html", prob.name.to_lowercase());
-        plot.write_html(&filename);
-        println!("  Saved plot to {}", filename);
+        if cli.animate {
+            write_evolution(&prob, &cli, &x_space, &y_space, &z)?;
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ref_set(points: &[[f64; 2]]) -> Vec<(Array1<f64>, f64)> {
+        points.iter().map(|p| (Array1::from_vec(p.to_vec()), 0.0)).collect()
+    }
+
+    #[test]
+    fn diversity_is_the_mean_pairwise_distance_over_the_diagonal() {
+        let bounds = [[0.0, 4.0], [0.0, 3.0]];
+        // Pairwise distances 5, 4 and 3 on a domain with a diagonal of 5
+        let set = ref_set(&[[0.0, 0.0], [4.0, 3.0], [4.0, 0.0]]);
+        assert!((reference_set_diversity(&set, &bounds) - 0.8).abs() < 1e-12);
+        assert_eq!(reference_set_diversity(&set[..1], &bounds), 0.0);
+    }
+
+    #[test]
+    fn coverage_counts_occupied_cells_once() {
+        let bounds = [[0.0, 4.0], [0.0, 3.0]];
+        // (1, 1) shares the lower-left cell with the origin, and points on the
+        // upper bounds fall into the last cells
+        let set = ref_set(&[[0.0, 0.0], [1.0, 1.0], [4.0, 3.0], [4.0, 0.0]]);
+        assert_eq!(reference_set_coverage(&set, &bounds, 2), 0.75);
+        assert_eq!(reference_set_coverage(&set, &bounds, 1), 1.0);
+        assert_eq!(reference_set_coverage(&[], &bounds, 2), 0.0);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+/// Benchmark settings loaded from a TOML file with `--config`. Every field is
+/// optional; flags given on the command line take precedence over the file.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub function: Option<String>,
+    pub dims: Option<Vec<usize>>,
+    pub runs: Option<usize>,
+    pub tolerance: Option<f64>,
+    #[serde(default)]
+    pub params: ParamsConfig,
+    #[serde(default)]
+    pub output: OutputConfig,
+}
+
+/// Overrides of the `OQNLPParams` fields, as with the corresponding flags.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ParamsConfig {
+    pub population_size: Option<usize>,
+    pub iterations: Option<usize>,
+    pub wait_cycle: Option<usize>,
+    pub threshold_factor: Option<f64>,
+    pub distance_factor: Option<f64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfig {
+    pub save_json: Option<String>,
+    pub save_csv: Option<String>,
+    pub markdown: Option<String>,
+    pub dump_raw: Option<String>,
+    pub export_table: Option<String>,
+}
+
+pub fn load(path: &str) -> Result<Config, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    basic_toml::from_str(&contents).map_err(|e| e.to_string())
+}
@@ -0,0 +1,73 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+/// Ackley N.2 function in 2D, an exponential bowl with minimum -200 at the origin.
+pub struct AckleyN2;
+
+impl BenchmarkFn for AckleyN2 {
+    fn name(&self) -> &str {
+        "AckleyN2"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -200.0
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![0.0, 0.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(AckleyN2Problem, seed, params, opts)
+    }
+}
+
+fn ackley_n2_local(x: &[f64; 2]) -> f64 {
+    -200.0 * (-0.02 * (x[0].powi(2) + x[1].powi(2)).sqrt()).exp()
+}
+
+#[derive(Clone)]
+struct AckleyN2Problem;
+
+impl Problem for AckleyN2Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(ackley_n2_local(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -32.0;
+            bounds[[i, 1]] = 32.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_origin_is_global_minimum() {
+        let obj = AckleyN2Problem.objective(&array![0.0, 0.0]).unwrap();
+        assert!((obj - AckleyN2.global_minimum(2)).abs() < 1e-12, "f(0, 0) = {}", obj);
+    }
+}
@@ -0,0 +1,90 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+/// One of the two optima, found numerically; the other mirrors it across the x2 axis.
+const OPTIMUM: [f64; 2] = [-1.5096201062202452, -0.7548651218414308];
+
+/// Ackley N.4 function in 2D, with minimum ≈ -4.5901 at `(±1.5096, -0.7549)`.
+pub struct AckleyN4;
+
+impl BenchmarkFn for AckleyN4 {
+    fn name(&self) -> &str {
+        "AckleyN4"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -4.590101634158668
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![OPTIMUM[0], OPTIMUM[1]])
+    }
+
+    fn nearest_argmin(&self, _dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        // The function is even in x1, so pick the optimum on the point's side
+        Some(array![OPTIMUM[0].abs().copysign(point[0]), OPTIMUM[1]])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(AckleyN4Problem, seed, params, opts)
+    }
+}
+
+fn ackley_n4_local(x: &[f64; 2]) -> f64 {
+    let [x1, x2] = *x;
+    (-0.2f64).exp() * (x1.powi(2) + x2.powi(2)).sqrt() + 3.0 * ((2.0 * x1).cos() + (2.0 * x2).sin())
+}
+
+#[derive(Clone)]
+struct AckleyN4Problem;
+
+impl Problem for AckleyN4Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(ackley_n4_local(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -35.0;
+            bounds[[i, 1]] = 35.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_both_optima_is_global_minimum() {
+        for x1 in [OPTIMUM[0], -OPTIMUM[0]] {
+            let obj = AckleyN4Problem.objective(&array![x1, OPTIMUM[1]]).unwrap();
+            assert!(
+                (obj - AckleyN4.global_minimum(2)).abs() < 1e-9,
+                "f({}, {}) = {}",
+                x1,
+                OPTIMUM[1],
+                obj
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Alpine N.1 function, with minimum 0 at the origin. The absolute value makes it
+/// non-differentiable at the optimum.
+pub struct AlpineN1;
+
+impl BenchmarkFn for AlpineN1 {
+    fn name(&self) -> &str {
+        "AlpineN1"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "multimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(AlpineN1Problem { dim }, seed, params, opts)
+    }
+}
+
+fn alpine_n1_local(x: &[f64]) -> f64 {
+    x.iter().map(|&xi| (xi * xi.sin() + 0.1 * xi).abs()).sum()
+}
+
+#[derive(Clone)]
+struct AlpineN1Problem {
+    dim: usize,
+}
+
+impl Problem for AlpineN1Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(alpine_n1_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -10.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = AlpineN1Problem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn objective_at_a_fixed_point() {
+        // |pi sin(pi) + 0.1 pi| + |-sin(1) - 0.1| = 0.1 pi + sin(1) - 0.1
+        let x = Array1::from_vec(vec![std::f64::consts::PI, -1.0]);
+        let obj = AlpineN1Problem { dim: 2 }.objective(&x).unwrap();
+        assert!((obj - 1.0556302501668757).abs() < 1e-12, "f(pi, -1) = {}", obj);
+    }
+}
@@ -0,0 +1,54 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::beale;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+pub struct Beale;
+
+impl BenchmarkFn for Beale {
+    fn name(&self) -> &str {
+        "Beale"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![3.0, 0.5])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(BealeProblem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct BealeProblem;
+
+impl Problem for BealeProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(beale(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -4.5;
+            bounds[[i, 1]] = 4.5;
+        }
+        bounds
+    }
+}
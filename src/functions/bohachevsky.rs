@@ -0,0 +1,105 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+use std::f64::consts::PI;
+
+/// How the two cosine terms of the Bohachevsky functions are combined.
+#[derive(Clone, Copy)]
+pub enum BohachevskyVariant {
+    /// Sum of the two cosines
+    N1,
+    /// Product of the two cosines
+    N2,
+    /// Single cosine of the combined argument
+    N3,
+}
+
+/// Bohachevsky functions in 2D, all with minimum 0 at the origin.
+pub struct Bohachevsky {
+    pub variant: BohachevskyVariant,
+}
+
+impl BenchmarkFn for Bohachevsky {
+    fn name(&self) -> &str {
+        match self.variant {
+            BohachevskyVariant::N1 => "Bohachevsky1",
+            BohachevskyVariant::N2 => "Bohachevsky2",
+            BohachevskyVariant::N3 => "Bohachevsky3",
+        }
+    }
+
+    fn tags(&self) -> &[&str] {
+        match self.variant {
+            BohachevskyVariant::N1 => &["separable", "multimodal", "low-dim-only"],
+            BohachevskyVariant::N2 | BohachevskyVariant::N3 => {
+                &["non-separable", "multimodal", "low-dim-only"]
+            }
+        }
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![0.0, 0.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(BohachevskyProblem { variant: self.variant }, seed, params, opts)
+    }
+}
+
+fn bohachevsky_local(x: &[f64; 2], variant: BohachevskyVariant) -> f64 {
+    let [x1, x2] = *x;
+    let bowl = x1.powi(2) + 2.0 * x2.powi(2);
+    match variant {
+        BohachevskyVariant::N1 => {
+            bowl - 0.3 * (3.0 * PI * x1).cos() - 0.4 * (4.0 * PI * x2).cos() + 0.7
+        }
+        BohachevskyVariant::N2 => bowl - 0.3 * (3.0 * PI * x1).cos() * (4.0 * PI * x2).cos() + 0.3,
+        BohachevskyVariant::N3 => bowl - 0.3 * (3.0 * PI * x1 + 4.0 * PI * x2).cos() + 0.3,
+    }
+}
+
+#[derive(Clone)]
+struct BohachevskyProblem {
+    variant: BohachevskyVariant,
+}
+
+impl Problem for BohachevskyProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(bohachevsky_local(&arr, self.variant))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_origin_for_each_variant() {
+        for variant in [BohachevskyVariant::N1, BohachevskyVariant::N2, BohachevskyVariant::N3] {
+            let obj = BohachevskyProblem { variant }.objective(&array![0.0, 0.0]).unwrap();
+            let name = Bohachevsky { variant }.name().to_string();
+            assert!(obj.abs() < 1e-12, "{}: f(0, 0) = {}", name, obj);
+        }
+    }
+}
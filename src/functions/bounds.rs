@@ -0,0 +1,77 @@
+use globalsearch::problem::Problem;
+use globalsearch::types::EvaluationError;
+use ndarray::{Array1, Array2};
+
+/// Wraps a problem and scales the width of its variable bounds by `factor` around
+/// their center, widening (`factor > 1`) or narrowing (`factor < 1`) the search domain.
+#[derive(Clone)]
+pub struct ScaledBoundsProblem<P> {
+    inner: P,
+    factor: f64,
+}
+
+impl<P: Problem> ScaledBoundsProblem<P> {
+    pub fn new(inner: P, factor: f64) -> Self {
+        Self { inner, factor }
+    }
+}
+
+impl<P: Problem> Problem for ScaledBoundsProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        self.inner.objective(x)
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        self.inner.gradient(x)
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        self.inner.hessian(x)
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        scale_bounds(&self.inner.variable_bounds(), self.factor)
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+/// `bounds` with the interval of every variable scaled by `factor` around its center.
+pub fn scale_bounds(bounds: &Array2<f64>, factor: f64) -> Array2<f64> {
+    let mut scaled = bounds.clone();
+    for mut row in scaled.rows_mut() {
+        let center = 0.5 * (row[0] + row[1]);
+        let half_width = 0.5 * (row[1] - row[0]) * factor;
+        row[0] = center - half_width;
+        row[1] = center + half_width;
+    }
+    scaled
+}
+
+/// Whether `point` lies within `bounds` along every axis.
+pub fn within_bounds(bounds: &Array2<f64>, point: &Array1<f64>) -> bool {
+    bounds.rows().into_iter().zip(point).all(|(row, &x)| row[0] <= x && x <= row[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Quadratic;
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn bounds_are_scaled_around_their_center() {
+        let bounds = array![[0.0, 4.0], [-3.0, -1.0]];
+        assert_eq!(scale_bounds(&bounds, 0.5), array![[1.0, 3.0], [-2.5, -1.5]]);
+        assert_eq!(scale_bounds(&bounds, 3.0), array![[-4.0, 8.0], [-5.0, 1.0]]);
+        assert_eq!(scale_bounds(&bounds, 1.0), bounds);
+
+        let base = Quadratic::new(&[1.0, -2.0]);
+        let scaled = ScaledBoundsProblem::new(base.clone(), 2.0);
+        assert_eq!(scaled.variable_bounds(), array![[-10.0, 10.0], [-10.0, 10.0]]);
+        let x = array![7.5, -8.0];
+        assert_eq!(scaled.objective(&x).unwrap(), base.objective(&x).unwrap());
+    }
+}
@@ -0,0 +1,91 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+use std::f64::consts::PI;
+
+/// The three equivalent global minima.
+const OPTIMA: [[f64; 2]; 3] = [[-PI, 12.275], [PI, 2.275], [3.0 * PI, 2.475]];
+
+pub struct Branin;
+
+impl BenchmarkFn for Branin {
+    fn name(&self) -> &str {
+        "Branin"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        5.0 / (4.0 * PI)
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![OPTIMA[0][0], OPTIMA[0][1]])
+    }
+
+    fn nearest_argmin(&self, _dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        let dist = |opt: &[f64; 2]| (opt[0] - point[0]).powi(2) + (opt[1] - point[1]).powi(2);
+        OPTIMA.iter().min_by(|a, b| dist(a).total_cmp(&dist(b))).map(|opt| array![opt[0], opt[1]])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(BraninProblem, seed, params, opts)
+    }
+}
+
+fn branin(x: &[f64; 2]) -> f64 {
+    let [x1, x2] = *x;
+    let a = 1.0;
+    let b = 5.1 / (4.0 * PI.powi(2));
+    let c = 5.0 / PI;
+    let r = 6.0;
+    let s = 10.0;
+    let t = 1.0 / (8.0 * PI);
+    a * (x2 - b * x1.powi(2) + c * x1 - r).powi(2) + s * (1.0 - t) * x1.cos() + s
+}
+
+#[derive(Clone)]
+struct BraninProblem;
+
+impl Problem for BraninProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(branin(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        bounds[[0, 0]] = -5.0;
+        bounds[[0, 1]] = 10.0;
+        bounds[[1, 0]] = 0.0;
+        bounds[[1, 1]] = 15.0;
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_each_optimum_matches_minimum() {
+        for opt in OPTIMA {
+            let obj = BraninProblem.objective(&array![opt[0], opt[1]]).unwrap();
+            assert!((obj - Branin.global_minimum(2)).abs() < 1e-12, "f({:?}) = {}", opt, obj);
+            assert!((obj - 0.397887).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::bukin_n6;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+pub struct BukinN6;
+
+impl BenchmarkFn for BukinN6 {
+    fn name(&self) -> &str {
+        "BukinN6"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![-10.0, 1.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(BukinN6Problem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct BukinN6Problem;
+
+impl Problem for BukinN6Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(bukin_n6(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        bounds[[0, 0]] = -15.0;
+        bounds[[0, 1]] = -5.0;
+        bounds[[1, 0]] = -3.0;
+        bounds[[1, 1]] = 3.0;
+        bounds
+    }
+}
@@ -0,0 +1,66 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Chung-Reynolds function, the squared sphere, with minimum 0 at the origin.
+pub struct ChungReynolds;
+
+impl BenchmarkFn for ChungReynolds {
+    fn name(&self) -> &str {
+        "ChungReynolds"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(ChungReynoldsProblem { dim }, seed, params, opts)
+    }
+}
+
+fn chung_reynolds_local(x: &[f64]) -> f64 {
+    x.iter().map(|xi| xi.powi(2)).sum::<f64>().powi(2)
+}
+
+#[derive(Clone)]
+struct ChungReynoldsProblem {
+    dim: usize,
+}
+
+impl Problem for ChungReynoldsProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(chung_reynolds_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = ChungReynoldsProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+}
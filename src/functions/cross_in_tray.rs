@@ -1,11 +1,8 @@
-use super::{BenchmarkFn, RunResult};
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
 use argmin_testfunctions::cross_in_tray;
-use globalsearch::observers::Observer;
-use globalsearch::oqnlp::OQNLP;
 use globalsearch::problem::Problem;
 use globalsearch::types::{EvaluationError, OQNLPParams};
-use ndarray::{Array1, Array2};
-use std::time::{Duration, Instant};
+use ndarray::{Array1, Array2, array};
 
 pub struct CrossInTray;
 
@@ -14,46 +11,31 @@ impl BenchmarkFn for CrossInTray {
         "CrossInTray"
     }
 
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
     fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
         vec![2]
     }
 
-    fn run(&self, _dim: usize, seed: u64) -> RunResult {
-        let problem = CrossInTrayProblem;
-        let params = OQNLPParams { seed, ..OQNLPParams::default() };
-
-        let observer = Observer::new().with_stage1_tracking().with_stage2_tracking().with_timing();
-        let mut optimizer =
-            OQNLP::new(problem, params).expect("Failed to create OQNLP").add_observer(observer);
-
-        let start = Instant::now();
-        let solution_set = std::hint::black_box(optimizer.run()).expect("OQNLP run failed");
-        let duration = start.elapsed();
-
-        let obs = optimizer.observer().unwrap();
-        let stage1_duration = obs
-            .stage1_final()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
-        let stage2_duration = obs
-            .stage2()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -2.06261
+    }
 
-        let best_sol = solution_set.best_solution().expect("No solutions found");
-        let obj = best_sol.objective;
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        // One of the four symmetric optima
+        Some(array![1.34941, 1.34941])
+    }
 
-        // Global min is -2.06261
-        RunResult {
-            success: (obj - (-2.06261)).abs() < 1e-4,
-            runtime: duration,
-            stage1_runtime: stage1_duration,
-            stage2_runtime: stage2_duration,
-            best_obj: obj,
-            solution_set_size: solution_set.len(),
-        }
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(CrossInTrayProblem, seed, params, opts)
     }
 }
 
@@ -62,9 +44,7 @@ struct CrossInTrayProblem;
 
 impl Problem for CrossInTrayProblem {
     fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
-        let arr: [f64; 2] = x.as_slice().and_then(|s| s.try_into().ok()).ok_or_else(|| {
-            EvaluationError::InvalidInput { reason: "Expected 2D point".to_string() }
-        })?;
+        let arr = to_2d_array(x)?;
         Ok(cross_in_tray(&arr))
     }
 
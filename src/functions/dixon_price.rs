@@ -0,0 +1,75 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct DixonPrice;
+
+impl BenchmarkFn for DixonPrice {
+    fn name(&self) -> &str {
+        "DixonPrice"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        // x_i = 2^(-(2^i - 2) / 2^i), with 1-based indices
+        Some(Array1::from_shape_fn(dim, |i| {
+            let p = 2f64.powi(i as i32 + 1);
+            2f64.powf(-(p - 2.0) / p)
+        }))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(DixonPriceProblem { dim }, seed, params, opts)
+    }
+}
+
+fn dixon_price_local(x: &[f64]) -> f64 {
+    let coupled: f64 = x
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| (i + 2) as f64 * (2.0 * w[1].powi(2) - w[0]).powi(2))
+        .sum();
+    (x[0] - 1.0).powi(2) + coupled
+}
+
+#[derive(Clone)]
+struct DixonPriceProblem {
+    dim: usize,
+}
+
+impl Problem for DixonPriceProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(dixon_price_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -10.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_form_optimum_is_near_zero() {
+        let argmin = DixonPrice.global_argmin(3).unwrap();
+        let obj = DixonPriceProblem { dim: 3 }.objective(&argmin).unwrap();
+        assert!(obj.abs() < 1e-12, "f(x*) = {}", obj);
+    }
+}
@@ -0,0 +1,62 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+pub struct DropWave;
+
+impl BenchmarkFn for DropWave {
+    fn name(&self) -> &str {
+        "DropWave"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -1.0
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![0.0, 0.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(DropWaveProblem, seed, params, opts)
+    }
+}
+
+pub fn drop_wave_local(x: &[f64; 2]) -> f64 {
+    let r_sq = x[0].powi(2) + x[1].powi(2);
+    -(1.0 + (12.0 * r_sq.sqrt()).cos()) / (0.5 * r_sq + 2.0)
+}
+
+#[derive(Clone)]
+struct DropWaveProblem;
+
+impl Problem for DropWaveProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(drop_wave_local(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -5.12;
+            bounds[[i, 1]] = 5.12;
+        }
+        bounds
+    }
+}
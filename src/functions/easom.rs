@@ -0,0 +1,74 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::easom;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+use std::f64::consts::PI;
+
+/// Easom function: flat almost everywhere, with the optimum in a narrow spike
+/// around (π, π).
+pub struct Easom;
+
+impl BenchmarkFn for Easom {
+    fn name(&self) -> &str {
+        "Easom"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -1.0
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![PI, PI])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(EasomProblem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct EasomProblem;
+
+impl Problem for EasomProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(easom(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_flat_away_from_optimum() {
+        for point in [array![0.0, 0.0], array![-50.0, 20.0], array![PI + 10.0, PI]] {
+            let obj = EasomProblem.objective(&point).unwrap();
+            assert!(obj.abs() < 1e-6, "f({}) = {}", point, obj);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::holder_table;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+const OPTIMUM: [f64; 2] = [8.05502, 9.66459];
+
+pub struct HolderTable;
+
+impl BenchmarkFn for HolderTable {
+    fn name(&self) -> &str {
+        "HolderTable"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -19.2085
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        // One of the four symmetric optima
+        Some(array![OPTIMUM[0], OPTIMUM[1]])
+    }
+
+    fn nearest_argmin(&self, _dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        // The optima are mirrored across both axes, so pick the one in the point's quadrant
+        Some(array![OPTIMUM[0].copysign(point[0]), OPTIMUM[1].copysign(point[1])])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(HolderTableProblem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct HolderTableProblem;
+
+impl Problem for HolderTableProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(holder_table(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -10.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_each_mirrored_optimum_matches_minimum() {
+        for (sx, sy) in [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)] {
+            let argmin = HolderTable.nearest_argmin(2, &array![sx, sy]).unwrap();
+            assert_eq!(argmin, array![sx * OPTIMUM[0], sy * OPTIMUM[1]]);
+            let obj = HolderTableProblem.objective(&argmin).unwrap();
+            assert!((obj - HolderTable.global_minimum(2)).abs() < 1e-4, "f({}) = {}", argmin, obj);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+use std::f64::consts::PI;
+
+/// Centers of the five terms.
+const A: [[f64; 2]; 5] = [[3.0, 5.0], [5.0, 2.0], [2.0, 1.0], [1.0, 4.0], [7.0, 9.0]];
+
+/// Weights of the five terms.
+const C: [f64; 5] = [1.0, 2.0, 5.0, 2.0, 3.0];
+
+/// Location of the global minimum, found numerically.
+const OPTIMUM: [f64; 2] = [2.7934022102, 1.5972324982];
+
+/// Langermann function in 2D with the common five-term parameters.
+pub struct Langermann;
+
+impl BenchmarkFn for Langermann {
+    fn name(&self) -> &str {
+        "Langermann"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -4.15580929184779
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::from(OPTIMUM.to_vec()))
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(LangermannProblem, seed, params, opts)
+    }
+}
+
+fn langermann_local(x: &[f64; 2]) -> f64 {
+    A.iter()
+        .zip(C)
+        .map(|(a, c)| {
+            let dist_sq: f64 = x.iter().zip(a).map(|(xj, aj)| (xj - aj).powi(2)).sum();
+            c * (-dist_sq / PI).exp() * (PI * dist_sq).cos()
+        })
+        .sum()
+}
+
+#[derive(Clone)]
+struct LangermannProblem;
+
+impl Problem for LangermannProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(langermann_local(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = 0.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_documented_optimum_matches_minimum() {
+        let obj = LangermannProblem.objective(&Langermann.global_argmin(2).unwrap()).unwrap();
+        assert!((obj - Langermann.global_minimum(2)).abs() < 1e-8, "f(x*) = {}", obj);
+    }
+}
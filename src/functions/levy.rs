@@ -1,11 +1,8 @@
-use super::{BenchmarkFn, RunResult};
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
 use argmin_testfunctions::levy;
-use globalsearch::observers::Observer;
-use globalsearch::oqnlp::OQNLP;
 use globalsearch::problem::Problem;
 use globalsearch::types::{EvaluationError, OQNLPParams};
 use ndarray::{Array1, Array2};
-use std::time::{Duration, Instant};
 
 pub struct Levy;
 
@@ -14,41 +11,22 @@ impl BenchmarkFn for Levy {
         "Levy"
     }
 
-    fn run(&self, dim: usize, seed: u64) -> RunResult {
-        let problem = LevyProblem { dim };
-        let params = OQNLPParams { seed, ..OQNLPParams::default() };
-
-        let observer = Observer::new().with_stage1_tracking().with_stage2_tracking().with_timing();
-        let mut optimizer =
-            OQNLP::new(problem, params).expect("Failed to create OQNLP").add_observer(observer);
-
-        let start = Instant::now();
-        let solution_set = std::hint::black_box(optimizer.run()).expect("OQNLP run failed");
-        let duration = start.elapsed();
-
-        let obs = optimizer.observer().unwrap();
-        let stage1_duration = obs
-            .stage1_final()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
-        let stage2_duration = obs
-            .stage2()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal"]
+    }
 
-        let best_sol = solution_set.best_solution().expect("No solutions found");
-        let obj = best_sol.objective;
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::ones(dim))
+    }
 
-        RunResult {
-            success: obj < 1e-4,
-            runtime: duration,
-            stage1_runtime: stage1_duration,
-            stage2_runtime: stage2_duration,
-            best_obj: obj,
-            solution_set_size: solution_set.len(),
-        }
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(LevyProblem { dim }, seed, params, opts)
     }
 }
 
@@ -0,0 +1,58 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::mccormick;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+pub struct McCormick;
+
+impl BenchmarkFn for McCormick {
+    fn name(&self) -> &str {
+        "McCormick"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -1.9132229
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![-0.54719, -1.54719])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(McCormickProblem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct McCormickProblem;
+
+impl Problem for McCormickProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(mccormick(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        bounds[[0, 0]] = -1.5;
+        bounds[[0, 1]] = 4.0;
+        bounds[[1, 0]] = -3.0;
+        bounds[[1, 1]] = 4.0;
+        bounds
+    }
+}
@@ -0,0 +1,95 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+use std::f64::consts::PI;
+
+/// Default steepness of the valleys and ridges.
+const DEFAULT_M: i32 = 10;
+
+/// Known global minima for `m = 10`, keyed by dimension.
+pub const MICHALEWICZ_MINIMA: [(usize, f64); 3] = [(2, -1.8013034), (5, -4.687658), (10, -9.66015)];
+
+pub struct Michalewicz;
+
+impl BenchmarkFn for Michalewicz {
+    fn name(&self) -> &str {
+        "Michalewicz"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "multimodal"]
+    }
+
+    // Only dimensions with a known minimum are run, so success is always judged
+    // against an exact value with the configured tolerance
+    fn supported_dims(&self, default_dims: &[usize]) -> Vec<usize> {
+        default_dims.iter().copied().filter(|&dim| tabulated_minimum(dim).is_some()).collect()
+    }
+
+    fn global_minimum(&self, dim: usize) -> f64 {
+        tabulated_minimum(dim).unwrap_or(f64::NAN)
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(MichalewiczProblem { dim, m: DEFAULT_M }, seed, params, opts)
+    }
+}
+
+fn michalewicz_local(x: &[f64], m: i32) -> f64 {
+    -x.iter()
+        .enumerate()
+        .map(|(i, &xi)| xi.sin() * ((i + 1) as f64 * xi.powi(2) / PI).sin().powi(2 * m))
+        .sum::<f64>()
+}
+
+fn tabulated_minimum(dim: usize) -> Option<f64> {
+    MICHALEWICZ_MINIMA.iter().find(|(d, _)| *d == dim).map(|&(_, min)| min)
+}
+
+#[derive(Clone)]
+struct MichalewiczProblem {
+    dim: usize,
+    m: i32,
+}
+
+impl Problem for MichalewiczProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(michalewicz_local(x.as_slice().unwrap(), self.m))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = 0.0;
+            bounds[[i, 1]] = PI;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn objective_matches_tabulated_2d_minimum() {
+        let problem = MichalewiczProblem { dim: 2, m: DEFAULT_M };
+        let obj = problem.objective(&array![2.20290552014618, PI / 2.0]).unwrap();
+        assert!((obj - Michalewicz.global_minimum(2)).abs() < 1e-6, "f(x*) = {}", obj);
+    }
+
+    #[test]
+    fn tabulated_minima_for_2d_and_5d() {
+        assert_eq!(tabulated_minimum(2), Some(-1.8013034));
+        assert_eq!(tabulated_minimum(5), Some(-4.687658));
+        assert_eq!(Michalewicz.supported_dims(&[5, 10, 50, 100]), vec![5, 10]);
+    }
+}
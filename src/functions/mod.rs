@@ -1,26 +1,896 @@
-use std::time::Duration;
+use bounds::{ScaledBoundsProblem, within_bounds};
+use clap::ValueEnum;
+use globalsearch::local_solver::builders::LBFGSBuilder;
+use globalsearch::observers::Observer;
+use globalsearch::oqnlp::{OQNLP, OQNLPError};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+use negate::NegatedProblem;
+use noise::NoisyProblem;
+use rotation::{RotatedProblem, rotation_matrix};
+use shift::ShiftedProblem;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 pub mod ackley;
+pub mod ackley_n2;
+pub mod ackley_n4;
+pub mod alpine_n1;
+pub mod beale;
+pub mod bohachevsky;
+pub mod booth;
+pub mod bounds;
+pub mod branin;
+pub mod bukin_n6;
+pub mod chung_reynolds;
 pub mod cross_in_tray;
+pub mod dixon_price;
+pub mod drop_wave;
+pub mod easom;
+pub mod eggholder;
+pub mod goldstein_price;
 pub mod griewank;
+pub mod holder_table;
+pub mod langermann;
 pub mod levy;
+pub mod levy_n13;
+pub mod matyas;
+pub mod mccormick;
+pub mod michalewicz;
+pub mod negate;
+pub mod noise;
+pub mod perm0;
+pub mod permdb;
+pub mod qing;
 pub mod rastrigin;
 pub mod rosenbrock;
+pub mod rotated_hyper_ellipsoid;
+pub mod rotation;
+pub mod salomon;
+pub mod schaffer_n2;
+pub mod schaffer_n4;
+pub mod schwefel;
+pub mod shekel;
+pub mod shift;
 pub mod six_hump_camel;
+pub mod sphere;
+pub mod styblinski_tang;
+pub mod sum_diff_powers;
+pub mod sum_squares;
+pub mod three_hump_camel;
+pub mod trid;
+pub mod xin_she_yang;
+pub mod zakharov;
 
 pub struct RunResult {
-    pub success: bool,
     pub runtime: Duration,
+    /// CPU time of the optimizing thread, where the platform can measure it
+    pub cpu_time: Option<Duration>,
+    /// Peak resident memory of the process during the run, where the platform can measure it
+    pub peak_memory_bytes: Option<u64>,
     pub stage1_runtime: Duration,
     pub stage2_runtime: Duration,
     pub best_obj: f64,
+    pub best_point: Array1<f64>,
     pub solution_set_size: usize,
+    /// Noise-free objective of every point in the solution set
+    pub solution_objectives: Vec<f64>,
+    /// Smallest distance between two points of the solution set, if it has at least two
+    pub min_pairwise_distance: Option<f64>,
+    pub function_evaluations: u64,
+    /// Best objective in the solution set after each stage-2 iteration
+    pub convergence: Vec<f64>,
+    /// Whether stage 2 ran into `RunOptions::budget_iters` or `RunOptions::budget_sec`
+    pub budget_exhausted: bool,
 }
 
+/// Transformations applied to the problem of every benchmark run.
+#[derive(Clone)]
+pub struct RunOptions {
+    /// Standard deviation of the Gaussian noise added to each objective evaluation
+    pub noise_sigma: f64,
+    /// Offset added to every coordinate of the optimum
+    pub shift: f64,
+    /// Evaluate the function in a randomly rotated coordinate system
+    pub rotate: bool,
+    /// Maximize the objective, by minimizing its negation
+    pub maximize: bool,
+    /// Factor applied to the width of the variable bounds around their center
+    pub bounds_scale: f64,
+    /// Optimum of the transformed problem, checked to stay inside its bounds
+    pub optimum: Option<Array1<f64>>,
+    /// Cap on the stage-2 iterations, applied when below the configured iterations
+    pub budget_iters: Option<usize>,
+    /// Stage-2 time limit in seconds, checked by OQNLP between iterations
+    pub budget_sec: Option<f64>,
+    /// Let OQNLP print its progress
+    pub verbose: bool,
+    /// Once set, every objective evaluation fails so that OQNLP returns early
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl RunOptions {
+    /// Optimum of `func` nearest to `point`, carried through the configured
+    /// rotation and shift.
+    pub fn nearest_argmin(
+        &self,
+        func: &dyn BenchmarkFn,
+        dim: usize,
+        point: &Array1<f64>,
+    ) -> Option<Array1<f64>> {
+        let unshifted = point.mapv(|x| x - self.shift);
+        let argmin = match self.rotation(dim) {
+            Some(r) => r.t().dot(&func.nearest_argmin(dim, &r.dot(&unshifted))?),
+            None => func.nearest_argmin(dim, &unshifted)?,
+        };
+        Some(argmin.mapv(|x| x + self.shift))
+    }
+
+    /// Rotation matrix applied at `dim`, if rotation is enabled.
+    pub fn rotation(&self, dim: usize) -> Option<Arc<Array2<f64>>> {
+        self.rotate.then(|| Arc::new(rotation_matrix(dim)))
+    }
+}
+
+/// Reasons a single benchmark run can fail.
+#[derive(Debug)]
+pub enum BenchError {
+    Oqnlp(OQNLPError),
+    Evaluation(EvaluationError),
+    NoSolution,
+    /// The best solution's objective is NaN or infinite
+    NonFiniteObjective(f64),
+    /// Rotating, shifting or scaling the bounds left the optimum outside of them
+    OptimumOutOfBounds,
+}
+
+impl BenchError {
+    /// Whether the run finished but produced no usable solution, which a retry
+    /// with a different seed may avoid.
+    pub fn is_degenerate(&self) -> bool {
+        matches!(self, BenchError::NoSolution | BenchError::NonFiniteObjective(_))
+    }
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchError::Oqnlp(e) => write!(f, "{}", e),
+            BenchError::Evaluation(e) => write!(f, "{}", e),
+            BenchError::NoSolution => write!(f, "no solutions found"),
+            BenchError::NonFiniteObjective(obj) => write!(f, "best objective is {}", obj),
+            BenchError::OptimumOutOfBounds => {
+                write!(f, "the transformed bounds no longer contain the global optimum")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+impl From<OQNLPError> for BenchError {
+    fn from(e: OQNLPError) -> Self {
+        BenchError::Oqnlp(e)
+    }
+}
+
+impl From<EvaluationError> for BenchError {
+    fn from(e: EvaluationError) -> Self {
+        BenchError::Evaluation(e)
+    }
+}
+
+/// Categories a function can be tagged with, selectable with `--tag`.
+pub const TAGS: [&str; 5] =
+    ["separable", "non-separable", "unimodal", "multimodal", "low-dim-only"];
+
 pub trait BenchmarkFn: Send + Sync {
     fn name(&self) -> &str;
-    fn run(&self, dim: usize, seed: u64) -> RunResult;
+    /// Categories of the function, taken from [`TAGS`].
+    fn tags(&self) -> &[&str];
+    /// Runs OQNLP once with `params`, overriding its seed with `seed`, on the
+    /// problem transformed as described by `opts`.
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError>;
     fn supported_dims(&self, default_dims: &[usize]) -> Vec<usize> {
         default_dims.to_vec()
     }
+    /// Optimal objective value, which is the global maximum for maximization functions.
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        0.0
+    }
+    /// Whether the function is maximized rather than minimized. Its problem still
+    /// reports the objective as is; runs negate it for OQNLP and map the results back.
+    fn is_maximization(&self) -> bool {
+        false
+    }
+    /// Location of the global minimum, if known. Functions with several
+    /// equivalent optima return a canonical one.
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        None
+    }
+    /// Global minimizer closest to `point`, used for the distance metric.
+    /// Only functions that enumerate all of their equivalent optima need to override this.
+    fn nearest_argmin(&self, dim: usize, _point: &Array1<f64>) -> Option<Array1<f64>> {
+        self.global_argmin(dim)
+    }
+    fn success_tolerance(&self) -> f64 {
+        1e-4
+    }
+    fn is_success(&self, dim: usize, obj: f64, tol: f64) -> bool {
+        (obj - self.global_minimum(dim)).abs() < tol
+    }
+    /// Whether the problem implements an analytic gradient, so that it can
+    /// be benchmarked with a gradient-based local solver.
+    fn has_gradient(&self) -> bool {
+        false
+    }
+}
+
+/// How the best objective of a run is compared with the global minimum.
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum SuccessMetric {
+    /// The function's own criterion, by default `|obj - min| < tol`
+    #[default]
+    Absolute,
+    /// `|obj - min| / (|min| + eps) < tol`; functions whose minimum is exactly
+    /// zero fall back to the absolute criterion
+    Relative,
+}
+
+/// Guards the relative error against minima that are tiny but not zero.
+const RELATIVE_EPS: f64 = 1e-12;
+
+impl SuccessMetric {
+    pub fn is_success(self, func: &dyn BenchmarkFn, dim: usize, obj: f64, tol: f64) -> bool {
+        let min = func.global_minimum(dim);
+        match self {
+            SuccessMetric::Relative if min != 0.0 => {
+                (obj - min).abs() / (min.abs() + RELATIVE_EPS) < tol
+            }
+            _ => func.is_success(dim, obj, tol),
+        }
+    }
+
+    /// Number of `objectives` that count as a success, e.g. the near-optimal
+    /// points of a solution set.
+    pub fn count_successes(
+        self,
+        func: &dyn BenchmarkFn,
+        dim: usize,
+        objectives: &[f64],
+        tol: f64,
+    ) -> usize {
+        objectives.iter().filter(|&&obj| self.is_success(func, dim, obj, tol)).count()
+    }
+}
+
+/// Runs a function that has an analytic gradient with the L-BFGS local solver
+/// instead of the configured one, reported under its own name.
+pub struct WithGradients {
+    inner: Arc<dyn BenchmarkFn>,
+    name: String,
+}
+
+impl WithGradients {
+    pub fn new(inner: Arc<dyn BenchmarkFn>) -> Self {
+        let name = format!("{}_LBFGS", inner.name());
+        Self { inner, name }
+    }
+}
+
+impl BenchmarkFn for WithGradients {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tags(&self) -> &[&str] {
+        self.inner.tags()
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        let params =
+            OQNLPParams { local_solver_config: LBFGSBuilder::default().build(), ..params.clone() };
+        self.inner.run(dim, seed, &params, opts)
+    }
+
+    fn supported_dims(&self, default_dims: &[usize]) -> Vec<usize> {
+        self.inner.supported_dims(default_dims)
+    }
+
+    fn global_minimum(&self, dim: usize) -> f64 {
+        self.inner.global_minimum(dim)
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        self.inner.global_argmin(dim)
+    }
+
+    fn nearest_argmin(&self, dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        self.inner.nearest_argmin(dim, point)
+    }
+
+    fn success_tolerance(&self) -> f64 {
+        self.inner.success_tolerance()
+    }
+
+    fn is_success(&self, dim: usize, obj: f64, tol: f64) -> bool {
+        self.inner.is_success(dim, obj, tol)
+    }
+
+    fn is_maximization(&self) -> bool {
+        self.inner.is_maximization()
+    }
+
+    fn has_gradient(&self) -> bool {
+        true
+    }
+}
+
+/// Wraps a problem and counts objective evaluations across all of its clones.
+#[derive(Clone)]
+pub struct CountingProblem<P> {
+    inner: P,
+    evaluations: Arc<AtomicU64>,
+}
+
+impl<P> CountingProblem<P> {
+    pub fn new(inner: P) -> Self {
+        Self { inner, evaluations: Arc::new(AtomicU64::new(0)) }
+    }
+
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+}
+
+impl<P: Problem> Problem for CountingProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        self.evaluations.fetch_add(1, Ordering::Relaxed);
+        self.inner.objective(x)
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        self.inner.gradient(x)
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        self.inner.hessian(x)
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+/// Wraps a problem so that its run can be abandoned. OQNLP can't be stopped from
+/// outside, so once the flag is set every evaluation fails instead.
+#[derive(Clone)]
+pub struct CancellableProblem<P> {
+    inner: P,
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+impl<P> CancellableProblem<P> {
+    pub fn new(inner: P, cancel: Option<Arc<AtomicBool>>) -> Self {
+        Self { inner, cancel }
+    }
+
+    fn check(&self) -> Result<(), EvaluationError> {
+        match &self.cancel {
+            Some(cancel) if cancel.load(Ordering::Relaxed) => Err(EvaluationError::InvalidInput {
+                reason: "run cancelled after its timeout".to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<P: Problem> Problem for CancellableProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        self.check()?;
+        self.inner.objective(x)
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        self.check()?;
+        self.inner.gradient(x)
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        self.check()?;
+        self.inner.hessian(x)
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+/// Runs OQNLP on `problem` with the given seed and collects the timing and
+/// solution statistics shared by every benchmark function. With noise enabled,
+/// the reported best objective is the noise-free value at the best point.
+pub fn run_oqnlp<P: Problem + Clone + Send + Sync>(
+    problem: P,
+    seed: u64,
+    params: &OQNLPParams,
+    opts: &RunOptions,
+) -> Result<RunResult, BenchError> {
+    let rotation = opts.rotation(problem.variable_bounds().nrows());
+    let shifted = ShiftedProblem::new(RotatedProblem::new(problem, rotation), opts.shift);
+    let transformed = ScaledBoundsProblem::new(shifted, opts.bounds_scale);
+    // Rotating and shifting move the optimum while keeping the bounds, and scaling
+    // moves the bounds, so any of them can leave the optimum out of reach
+    if let Some(optimum) = &opts.optimum
+        && !within_bounds(&transformed.variable_bounds(), optimum)
+    {
+        return Err(BenchError::OptimumOutOfBounds);
+    }
+    let negated = NegatedProblem::new(transformed, opts.maximize);
+    let sign = negated.sign();
+    let noisy = NoisyProblem::new(negated, opts.noise_sigma, seed);
+    let counter = CountingProblem::new(noisy.clone());
+    let problem = CancellableProblem::new(counter.clone(), opts.cancel.clone());
+    // A budget at or above the configured iterations leaves stage 2 unchanged
+    let capped = opts.budget_iters.filter(|&b| b < params.iterations);
+    let iterations = capped.unwrap_or(params.iterations);
+    let params = OQNLPParams { seed, iterations, ..params.clone() };
+
+    // The observer only exposes the current stage-2 state, so the history is
+    // recorded from a callback invoked once per stage-2 iteration
+    let convergence = Arc::new(Mutex::new(Vec::new()));
+    let history = Arc::clone(&convergence);
+    // Stage 1's total_time keeps ticking after the stage ends, so it is
+    // snapshotted here; the last callback before stage 1 is marked complete
+    // fires right after it ends
+    let stage1_time = Arc::new(Mutex::new(None));
+    let stage1_snapshot = Arc::clone(&stage1_time);
+    let observer =
+        Observer::new().with_stage1_tracking().with_stage2_tracking().with_timing().with_callback(
+            move |obs| {
+                if let Some(stage1) = obs.stage1() {
+                    *stage1_snapshot.lock().unwrap() = stage1.total_time();
+                }
+                if let Some(stage2) = obs.stage2() {
+                    let best = stage2.best_objective();
+                    if !best.is_nan() {
+                        history.lock().unwrap().push(best);
+                    }
+                }
+            },
+        );
+    let mut optimizer = OQNLP::new(problem, params)?.add_observer(observer);
+    if let Some(budget) = opts.budget_sec {
+        optimizer = optimizer.max_time(budget);
+    }
+    if opts.verbose {
+        optimizer = optimizer.verbose();
+    }
+
+    reset_peak_memory();
+    let cpu_start = thread_cpu_time();
+    let start = Instant::now();
+    let solution_set = std::hint::black_box(optimizer.run())?;
+    let duration = start.elapsed();
+    let cpu_time = cpu_start.zip(thread_cpu_time()).map(|(start, end)| end.saturating_sub(start));
+    let peak_memory_bytes = peak_memory();
+
+    let obs = optimizer.observer().unwrap();
+    let stage1_duration = stage1_time
+        .lock()
+        .unwrap()
+        .or_else(|| obs.stage1_final().and_then(|s| s.total_time()))
+        .map(Duration::from_secs_f64)
+        .unwrap_or(Duration::ZERO);
+    let stage2_duration = obs
+        .stage2()
+        .and_then(|s| s.total_time())
+        .map(Duration::from_secs_f64)
+        .unwrap_or(Duration::ZERO);
+
+    let best_sol = solution_set.best_solution().ok_or(BenchError::NoSolution)?;
+    let best_obj = sign * noisy.clean_objective(&best_sol.point)?;
+    if !best_obj.is_finite() {
+        return Err(BenchError::NonFiniteObjective(best_obj));
+    }
+
+    // Iterations are counted from the convergence history, which skips the rare
+    // iterations without a finite best objective. Only a budget that lowered the
+    // iterations can have stopped stage 2 early.
+    let budget_exhausted = opts.budget_sec.is_some_and(|b| stage2_duration.as_secs_f64() >= b)
+        || capped.is_some_and(|b| convergence.lock().unwrap().len() >= b);
+
+    let points: Vec<Array1<f64>> = solution_set.solutions().map(|s| s.point.clone()).collect();
+    let solution_objectives = points
+        .iter()
+        .map(|p| Ok(sign * noisy.clean_objective(p)?))
+        .collect::<Result<Vec<f64>, BenchError>>()?;
+
+    Ok(RunResult {
+        runtime: duration,
+        cpu_time,
+        peak_memory_bytes,
+        stage1_runtime: stage1_duration,
+        stage2_runtime: stage2_duration,
+        best_obj,
+        best_point: best_sol.point.clone(),
+        solution_set_size: solution_set.len(),
+        solution_objectives,
+        min_pairwise_distance: min_pairwise_distance(&points),
+        function_evaluations: counter.evaluations(),
+        convergence: convergence.lock().unwrap().iter().map(|obj| sign * obj).collect(),
+        budget_exhausted,
+    })
+}
+
+/// Coordinates of a point passed to a 2D problem. Points of any other dimension are
+/// rejected with an error naming the length received.
+pub fn to_2d_array(x: &Array1<f64>) -> Result<[f64; 2], EvaluationError> {
+    if x.len() != 2 {
+        return Err(EvaluationError::InvalidInput {
+            reason: format!("Expected 2D point, got length {}", x.len()),
+        });
+    }
+    Ok([x[0], x[1]])
+}
+
+/// Smallest Euclidean distance between any two of `points`, or `None` with fewer
+/// than two points. A value near zero means the set holds near-duplicates.
+pub fn min_pairwise_distance(points: &[Array1<f64>]) -> Option<f64> {
+    points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            points[i + 1..].iter().map(move |b| (a - b).mapv(|d| d * d).sum().sqrt())
+        })
+        .min_by(f64::total_cmp)
+}
+
+/// CPU time consumed so far by the calling thread.
+#[cfg(unix)]
+fn thread_cpu_time() -> Option<Duration> {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: `ts` is a valid, writable timespec for the duration of the call
+    let ret = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+    (ret == 0).then(|| Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+}
+
+#[cfg(not(unix))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Resets the peak resident memory of the process, so that `peak_memory`
+/// reports the peak of the next run alone.
+#[cfg(target_os = "linux")]
+fn reset_peak_memory() {
+    // Writing 5 resets VmHWM; this fails harmlessly on kernels without support
+    let _ = std::fs::write("/proc/self/clear_refs", "5");
+}
+
+#[cfg(not(target_os = "linux"))]
+fn reset_peak_memory() {}
+
+/// Peak resident memory of the process in bytes. On Linux this is the peak since the
+/// last `reset_peak_memory`; elsewhere `ru_maxrss` only grows over the process lifetime.
+#[cfg(target_os = "linux")]
+fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kib: u64 =
+        line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim().parse().ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn peak_memory() -> Option<u64> {
+    // SAFETY: an all-zero rusage is a valid value for getrusage to overwrite
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `usage` is a valid, writable rusage for the duration of the call
+    let ret = unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) };
+    // ru_maxrss is in bytes on macOS and in kilobytes on the BSDs
+    let unit = if cfg!(target_os = "macos") { 1 } else { 1024 };
+    (ret == 0).then(|| usage.ru_maxrss as u64 * unit)
+}
+
+#[cfg(not(unix))]
+fn peak_memory() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `sum (x_i - c_i)^2` over `[-5, 5]^dim`, minimized at the center `c`. Shared
+    /// with the tests of the problem wrappers.
+    #[derive(Clone)]
+    pub(super) struct Quadratic {
+        center: Array1<f64>,
+    }
+
+    impl Quadratic {
+        pub(super) fn new(center: &[f64]) -> Self {
+            Self { center: Array1::from_vec(center.to_vec()) }
+        }
+    }
+
+    impl Problem for Quadratic {
+        fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+            Ok((x - &self.center).mapv(|d| d * d).sum())
+        }
+
+        fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+            Ok(2.0 * (x - &self.center))
+        }
+
+        fn variable_bounds(&self) -> Array2<f64> {
+            let mut bounds = Array2::zeros((self.center.len(), 2));
+            bounds.column_mut(0).fill(-5.0);
+            bounds.column_mut(1).fill(5.0);
+            bounds
+        }
+    }
+
+    impl BenchmarkFn for Quadratic {
+        fn name(&self) -> &str {
+            "Quadratic"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &["separable", "unimodal"]
+        }
+
+        fn run(
+            &self,
+            _dim: usize,
+            seed: u64,
+            params: &OQNLPParams,
+            opts: &RunOptions,
+        ) -> Result<RunResult, BenchError> {
+            run_oqnlp(self.clone(), seed, params, opts)
+        }
+
+        fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+            Some(self.center.clone())
+        }
+    }
+
+    /// `height - sum x_i^2` over `[-5, 5]^dim`, maximized at the origin.
+    #[derive(Clone)]
+    pub(super) struct Peak {
+        pub(super) dim: usize,
+        pub(super) height: f64,
+    }
+
+    impl Problem for Peak {
+        fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+            Ok(self.height - x.mapv(|xi| xi * xi).sum())
+        }
+
+        fn variable_bounds(&self) -> Array2<f64> {
+            let mut bounds = Array2::zeros((self.dim, 2));
+            bounds.column_mut(0).fill(-5.0);
+            bounds.column_mut(1).fill(5.0);
+            bounds
+        }
+    }
+
+    impl BenchmarkFn for Peak {
+        fn name(&self) -> &str {
+            "Peak"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &["separable", "unimodal"]
+        }
+
+        fn run(
+            &self,
+            _dim: usize,
+            seed: u64,
+            params: &OQNLPParams,
+            opts: &RunOptions,
+        ) -> Result<RunResult, BenchError> {
+            run_oqnlp(self.clone(), seed, params, opts)
+        }
+
+        fn global_minimum(&self, _dim: usize) -> f64 {
+            self.height
+        }
+
+        fn is_maximization(&self) -> bool {
+            true
+        }
+
+        fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+            Some(Array1::zeros(dim))
+        }
+    }
+
+    /// Options leaving the problem untransformed.
+    pub(super) fn plain_options() -> RunOptions {
+        RunOptions {
+            noise_sigma: 0.0,
+            shift: 0.0,
+            rotate: false,
+            maximize: false,
+            bounds_scale: 1.0,
+            optimum: None,
+            budget_iters: None,
+            budget_sec: None,
+            verbose: false,
+            cancel: None,
+        }
+    }
+
+    /// Checks the analytic gradient of `problem` against central differences at each point.
+    pub(super) fn assert_gradient_matches(problem: &impl Problem, points: &[&[f64]]) {
+        let h = 1e-6;
+        for &point in points {
+            let x = Array1::from_vec(point.to_vec());
+            let gradient = problem.gradient(&x).unwrap();
+            for i in 0..x.len() {
+                let mut step = Array1::zeros(x.len());
+                step[i] = h;
+                let numeric = (problem.objective(&(&x + &step)).unwrap()
+                    - problem.objective(&(&x - &step)).unwrap())
+                    / (2.0 * h);
+                let error = (gradient[i] - numeric).abs();
+                assert!(
+                    error <= 1e-5 * numeric.abs().max(1.0),
+                    "at {:?}, component {}: analytic {} vs numeric {}",
+                    point,
+                    i,
+                    gradient[i],
+                    numeric
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn objective_at_argmin_matches_minimum() {
+        use argmin_testfunctions as atf;
+        // The problem types are private to their modules, so each function is paired
+        // with the objective its problem evaluates
+        type Case = (&'static dyn BenchmarkFn, fn(&[f64]) -> f64);
+        let cases: [Case; 12] = [
+            (&beale::Beale, |x| atf::beale(&[x[0], x[1]])),
+            (&booth::Booth, |x| atf::booth(&[x[0], x[1]])),
+            (&bukin_n6::BukinN6, |x| atf::bukin_n6(&[x[0], x[1]])),
+            (&drop_wave::DropWave, |x| drop_wave::drop_wave_local(&[x[0], x[1]])),
+            (&easom::Easom, |x| atf::easom(&[x[0], x[1]])),
+            (&eggholder::Eggholder, |x| atf::eggholder(&[x[0], x[1]])),
+            (&goldstein_price::GoldsteinPrice, |x| atf::goldsteinprice(&[x[0], x[1]])),
+            (&levy_n13::LevyN13, |x| atf::levy_n13(&[x[0], x[1]])),
+            (&matyas::Matyas, |x| atf::matyas(&[x[0], x[1]])),
+            (&mccormick::McCormick, |x| atf::mccormick(&[x[0], x[1]])),
+            (&styblinski_tang::StyblinskiTang, styblinski_tang::styblinski_tang_local),
+            (&three_hump_camel::ThreeHumpCamel, |x| atf::threehumpcamel(&[x[0], x[1]])),
+        ];
+        for (func, objective) in cases {
+            let dim = func.supported_dims(&[10])[0];
+            let obj = objective(func.global_argmin(dim).unwrap().as_slice().unwrap());
+            let min = func.global_minimum(dim);
+            assert!((obj - min).abs() < 1e-6, "{}: f(x*) = {}, expected {}", func.name(), obj, min);
+        }
+    }
+
+    #[test]
+    fn looser_tolerance_raises_success_rate() {
+        // Best objectives of noisy runs scattered around Sphere's minimum of 0
+        let objectives = [1e-6, -5e-4, 2e-3, 0.05];
+        let rate = |tol| {
+            objectives
+                .iter()
+                .filter(|&&obj| SuccessMetric::Absolute.is_success(&sphere::Sphere, 10, obj, tol))
+                .count()
+        };
+        assert_eq!(rate(1e-4), 1);
+        assert_eq!(rate(1e-2), 3);
+        assert_eq!(rate(1.0), 4);
+    }
+
+    #[test]
+    fn counting_problem_counts_evaluations_of_all_clones() {
+        let counter = CountingProblem::new(Quadratic::new(&[0.0, 0.0]));
+        let clone = counter.clone();
+        let x = Array1::zeros(2);
+        counter.objective(&x).unwrap();
+        clone.objective(&x).unwrap();
+        clone.objective(&x).unwrap();
+        // Gradients aren't objective evaluations
+        let _ = clone.gradient(&x);
+        assert_eq!(counter.evaluations(), 3);
+    }
+
+    #[test]
+    fn duplicate_solutions_show_in_the_pairwise_distance_and_the_near_optimal_count() {
+        let solutions = [[0.0, 0.0], [3.0, 4.0], [1e-4, 0.0], [3.0, 4.0], [-1.0, 0.0]];
+        let points: Vec<Array1<f64>> =
+            solutions.iter().map(|p| Array1::from_vec(p.to_vec())).collect();
+        assert_eq!(min_pairwise_distance(&points), Some(0.0));
+        let distinct = [&points[0], &points[1], &points[4]].map(|p| p.clone());
+        assert_eq!(min_pairwise_distance(&distinct), Some(1.0));
+        assert_eq!(min_pairwise_distance(&points[..1]), None);
+
+        let objectives: Vec<f64> = solutions.iter().map(|p| p[0] * p[0] + p[1] * p[1]).collect();
+        let near_optimal =
+            |tol| SuccessMetric::Absolute.count_successes(&sphere::Sphere, 2, &objectives, tol);
+        // The origin and its near-duplicate count, the two copies of (3, 4) don't
+        assert_eq!(near_optimal(1e-6), 2);
+        assert_eq!(near_optimal(2.0), 3);
+        assert_eq!(near_optimal(30.0), 5);
+    }
+
+    #[test]
+    fn relative_metric_scales_the_tolerance_by_the_optimum() {
+        let (absolute, relative) = (SuccessMetric::Absolute, SuccessMetric::Relative);
+        // Styblinski-Tang's 2D minimum is about -78.33, so a gap of 0.5 is 0.64%
+        let st = &styblinski_tang::StyblinskiTang;
+        let obj = st.global_minimum(2) + 0.5;
+        assert!(!absolute.is_success(st, 2, obj, 1e-2));
+        assert!(relative.is_success(st, 2, obj, 1e-2));
+        assert!(!relative.is_success(st, 2, obj, 1e-3));
+
+        // A zero optimum falls back to the absolute criterion
+        for obj in [0.0, 1e-3, 0.5] {
+            for tol in [1e-2, 1.0] {
+                assert_eq!(
+                    relative.is_success(&sphere::Sphere, 2, obj, tol),
+                    absolute.is_success(&sphere::Sphere, 2, obj, tol)
+                );
+            }
+        }
+
+        // For a maximization the optimum is the maximum, approached from below
+        let peak = &Peak { dim: 2, height: 10.0 };
+        assert!(!absolute.is_success(peak, 2, 9.95, 1e-2));
+        assert!(relative.is_success(peak, 2, 9.95, 1e-2));
+        assert!(!relative.is_success(peak, 2, -10.0, 1e-2));
+    }
+
+    #[test]
+    fn best_point_has_the_problem_dimension() {
+        let params = OQNLPParams { population_size: 20, iterations: 2, ..OQNLPParams::default() };
+        for dim in [1, 2, 5] {
+            let func = Quadratic::new(&vec![0.5; dim]);
+            let res = func.run(dim, 0, &params, &plain_options()).unwrap();
+            assert_eq!(res.best_point.len(), dim);
+        }
+    }
+
+    #[test]
+    fn to_2d_array_names_the_length_it_got() {
+        assert_eq!(to_2d_array(&Array1::from_vec(vec![1.0, 2.0])).unwrap(), [1.0, 2.0]);
+        let Err(EvaluationError::InvalidInput { reason }) =
+            to_2d_array(&Array1::from_vec(vec![1.0, 2.0, 3.0]))
+        else {
+            panic!("accepted a 3D point");
+        };
+        assert!(reason.contains("length 3"), "{}", reason);
+    }
 }
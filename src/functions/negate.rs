@@ -0,0 +1,74 @@
+use globalsearch::problem::Problem;
+use globalsearch::types::EvaluationError;
+use ndarray::{Array1, Array2};
+
+/// Wraps a problem and optionally evaluates `-f(x)`, so that OQNLP, which always
+/// minimizes, maximizes the wrapped objective. Without negation the wrapped problem
+/// is evaluated unchanged.
+#[derive(Clone)]
+pub struct NegatedProblem<P> {
+    inner: P,
+    negate: bool,
+}
+
+impl<P: Problem> NegatedProblem<P> {
+    pub fn new(inner: P, negate: bool) -> Self {
+        Self { inner, negate }
+    }
+
+    /// `1.0`, or `-1.0` when negating, to map objectives back to the wrapped problem.
+    pub fn sign(&self) -> f64 {
+        if self.negate { -1.0 } else { 1.0 }
+    }
+}
+
+impl<P: Problem> Problem for NegatedProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(self.sign() * self.inner.objective(x)?)
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        Ok(self.sign() * self.inner.gradient(x)?)
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        Ok(self.sign() * self.inner.hessian(x)?)
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{Peak, plain_options};
+    use super::super::{BenchmarkFn, RunOptions, SuccessMetric};
+    use super::*;
+    use globalsearch::types::OQNLPParams;
+
+    #[test]
+    fn negation_flips_the_objective_only_when_enabled() {
+        let peak = Peak { dim: 2, height: 10.0 };
+        let x = Array1::from_vec(vec![1.0, 2.0]);
+        assert_eq!(NegatedProblem::new(peak.clone(), false).objective(&x).unwrap(), 5.0);
+        assert_eq!(NegatedProblem::new(peak, true).objective(&x).unwrap(), -5.0);
+    }
+
+    #[test]
+    fn maximized_run_reports_objectives_with_the_original_sign() {
+        let peak = Peak { dim: 2, height: 10.0 };
+        let params = OQNLPParams { population_size: 50, iterations: 5, ..OQNLPParams::default() };
+        let opts = RunOptions { maximize: peak.is_maximization(), ..plain_options() };
+        let res = peak.run(2, 0, &params, &opts).unwrap();
+
+        assert!((res.best_obj - 10.0).abs() < 1e-6, "best objective {}", res.best_obj);
+        assert!(SuccessMetric::Absolute.is_success(&peak, 2, res.best_obj, 1e-6));
+        assert!(res.solution_objectives.iter().all(|&obj| obj <= 10.0 && obj > 0.0));
+        assert!(res.convergence.iter().all(|&obj| obj > 0.0));
+    }
+}
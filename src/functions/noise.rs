@@ -0,0 +1,103 @@
+use globalsearch::problem::Problem;
+use globalsearch::types::EvaluationError;
+use ndarray::{Array1, Array2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+/// Wraps a problem and adds Gaussian noise with standard deviation `sigma` to
+/// every objective evaluation. The noise at a point only depends on the seed and
+/// the point, so runs are reproducible however the evaluations are scheduled.
+/// Gradients and Hessians are passed through noise-free.
+#[derive(Clone)]
+pub struct NoisyProblem<P> {
+    inner: P,
+    sigma: f64,
+    seed: u64,
+}
+
+impl<P: Problem> NoisyProblem<P> {
+    pub fn new(inner: P, sigma: f64, seed: u64) -> Self {
+        Self { inner, sigma, seed }
+    }
+
+    /// Objective of the wrapped problem without noise.
+    pub fn clean_objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        self.inner.objective(x)
+    }
+}
+
+/// Generator seeded from `seed` and the bits of `x`, drawing the same values for the
+/// same point no matter in which order or on which thread points are evaluated.
+pub fn point_rng<'a>(seed: u64, x: impl IntoIterator<Item = &'a f64>) -> StdRng {
+    // FNV-1a over 64-bit words, which unlike `DefaultHasher` is stable across Rust releases
+    let hash = x
+        .into_iter()
+        .map(|xi| xi.to_bits())
+        .fold(0xcbf2_9ce4_8422_2325 ^ seed, |h, word| (h ^ word).wrapping_mul(0x0100_0000_01b3));
+    StdRng::seed_from_u64(hash)
+}
+
+/// Standard normal sample using the Box-Muller transform.
+pub fn standard_normal(rng: &mut StdRng) -> f64 {
+    // 1 - u lies in (0, 1], keeping the logarithm finite
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+impl<P: Problem> Problem for NoisyProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let value = self.inner.objective(x)?;
+        if self.sigma == 0.0 {
+            return Ok(value);
+        }
+        // Offset from the run seed, so the noise doesn't reuse the weights Xin-She Yang N.1
+        // draws at the same point
+        let mut rng = point_rng(self.seed.wrapping_add(1), x);
+        Ok(value + self.sigma * standard_normal(&mut rng))
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        self.inner.gradient(x)
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        self.inner.hessian(x)
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Quadratic;
+    use super::*;
+
+    #[test]
+    fn zero_sigma_reproduces_the_base_function() {
+        let base = Quadratic::new(&[1.0, -2.0]);
+        let noisy = NoisyProblem::new(base.clone(), 0.0, 42);
+        for x in [[0.0, 0.0], [1.0, -2.0], [3.5, 4.25]] {
+            let x = Array1::from_vec(x.to_vec());
+            assert_eq!(noisy.objective(&x).unwrap(), base.objective(&x).unwrap());
+        }
+    }
+
+    #[test]
+    fn clean_objective_ignores_the_noise() {
+        let base = Quadratic::new(&[1.0, -2.0]);
+        let noisy = NoisyProblem::new(base.clone(), 1.0, 42);
+        let x = Array1::from_vec(vec![0.5, 0.5]);
+        assert_ne!(noisy.objective(&x).unwrap(), base.objective(&x).unwrap());
+        assert_eq!(noisy.clean_objective(&x).unwrap(), base.objective(&x).unwrap());
+        // The noise only depends on the seed and the point
+        assert_eq!(noisy.objective(&x).unwrap(), noisy.clone().objective(&x).unwrap());
+    }
+}
@@ -0,0 +1,103 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Perm function 0, d, β over [-d, d]^d.
+pub struct Perm0 {
+    pub beta: f64,
+}
+
+impl Default for Perm0 {
+    fn default() -> Self {
+        Perm0 { beta: 10.0 }
+    }
+}
+
+impl BenchmarkFn for Perm0 {
+    fn name(&self) -> &str {
+        "Perm0"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal", "low-dim-only"]
+    }
+
+    // Terms grow like d^d, overflowing to inf at the default dimensions
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2, 5, 10]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        // x_i = 1 / i, with 1-based indices
+        Some(Array1::from_shape_fn(dim, |i| 1.0 / (i + 1) as f64))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(Perm0Problem { dim, beta: self.beta }, seed, params, opts)
+    }
+}
+
+fn perm0_local(x: &[f64], beta: f64) -> f64 {
+    (1..=x.len() as i32)
+        .map(|i| {
+            let inner: f64 = x
+                .iter()
+                .enumerate()
+                .map(|(j, &xj)| {
+                    let j = (j + 1) as f64;
+                    (j + beta) * (xj.powi(i) - j.powi(-i))
+                })
+                .sum();
+            inner.powi(2)
+        })
+        .sum()
+}
+
+#[derive(Clone)]
+struct Perm0Problem {
+    dim: usize,
+    beta: f64,
+}
+
+impl Problem for Perm0Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(perm0_local(x.as_slice().unwrap(), self.beta))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let limit = self.dim as f64;
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -limit;
+            bounds[[i, 1]] = limit;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_form_optimum_is_near_zero() {
+        let argmin = Perm0::default().global_argmin(4).unwrap();
+        let obj = Perm0Problem { dim: 4, beta: 10.0 }.objective(&argmin).unwrap();
+        assert!(obj.abs() < 1e-12, "f(x*) = {}", obj);
+    }
+
+    #[test]
+    fn objective_stays_finite_at_supported_dims() {
+        for dim in Perm0::default().supported_dims(&[]) {
+            let corner = Array1::from_elem(dim, dim as f64);
+            assert!(Perm0Problem { dim, beta: 10.0 }.objective(&corner).unwrap().is_finite());
+        }
+    }
+}
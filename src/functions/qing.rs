@@ -0,0 +1,76 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Qing function, with minimum 0 wherever `x_i = ±sqrt(i)`, giving `2^d` global optima.
+pub struct Qing;
+
+impl BenchmarkFn for Qing {
+    fn name(&self) -> &str {
+        "Qing"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "multimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        // The optimum with every coordinate positive
+        Some(Array1::from_iter((1..=dim).map(|i| (i as f64).sqrt())))
+    }
+
+    fn nearest_argmin(&self, dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        // Each coordinate independently takes the optimal value on the point's side
+        Some(Array1::from_iter((1..=dim).map(|i| (i as f64).sqrt().copysign(point[i - 1]))))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(QingProblem { dim }, seed, params, opts)
+    }
+}
+
+fn qing_local(x: &[f64]) -> f64 {
+    x.iter().enumerate().map(|(i, &xi)| (xi.powi(2) - (i + 1) as f64).powi(2)).sum()
+}
+
+#[derive(Clone)]
+struct QingProblem {
+    dim: usize,
+}
+
+impl Problem for QingProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(qing_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -500.0;
+            bounds[[i, 1]] = 500.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_every_sign_of_the_optimum() {
+        let optimum = Qing.global_argmin(5).unwrap();
+        for signs in [[1.0; 5], [-1.0, 1.0, -1.0, 1.0, -1.0], [-1.0; 5]] {
+            let x = &optimum * &Array1::from_vec(signs.to_vec());
+            let obj = QingProblem { dim: 5 }.objective(&x).unwrap();
+            assert!(obj.abs() < 1e-12, "f({}) = {}", x, obj);
+        }
+    }
+}
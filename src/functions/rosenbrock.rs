@@ -1,11 +1,8 @@
-use super::{BenchmarkFn, RunResult};
-use argmin_testfunctions::rosenbrock;
-use globalsearch::observers::Observer;
-use globalsearch::oqnlp::OQNLP;
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use argmin_testfunctions::{rosenbrock, rosenbrock_derivative};
 use globalsearch::problem::Problem;
 use globalsearch::types::{EvaluationError, OQNLPParams};
 use ndarray::{Array1, Array2};
-use std::time::{Duration, Instant};
 
 pub struct Rosenbrock;
 
@@ -14,41 +11,26 @@ impl BenchmarkFn for Rosenbrock {
         "Rosenbrock"
     }
 
-    fn run(&self, dim: usize, seed: u64) -> RunResult {
-        let problem = RosenbrockProblem { dim };
-        let params = OQNLPParams { seed, ..OQNLPParams::default() };
-
-        let observer = Observer::new().with_stage1_tracking().with_stage2_tracking().with_timing();
-        let mut optimizer =
-            OQNLP::new(problem, params).expect("Failed to create OQNLP").add_observer(observer);
-
-        let start = Instant::now();
-        let solution_set = std::hint::black_box(optimizer.run()).expect("OQNLP run failed");
-        let duration = start.elapsed();
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
 
-        let obs = optimizer.observer().unwrap();
-        let stage1_duration = obs
-            .stage1_final()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
-        let stage2_duration = obs
-            .stage2()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::ones(dim))
+    }
 
-        let best_sol = solution_set.best_solution().expect("No solutions found");
-        let obj = best_sol.objective;
+    fn has_gradient(&self) -> bool {
+        true
+    }
 
-        RunResult {
-            success: obj < 1e-4,
-            runtime: duration,
-            stage1_runtime: stage1_duration,
-            stage2_runtime: stage2_duration,
-            best_obj: obj,
-            solution_set_size: solution_set.len(),
-        }
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(RosenbrockProblem { dim }, seed, params, opts)
     }
 }
 
@@ -62,6 +44,10 @@ impl Problem for RosenbrockProblem {
         Ok(rosenbrock(&x.to_vec()))
     }
 
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        Ok(Array1::from(rosenbrock_derivative(&x.to_vec())))
+    }
+
     fn variable_bounds(&self) -> Array2<f64> {
         let mut bounds = Array2::zeros((self.dim, 2));
         for i in 0..self.dim {
@@ -71,3 +57,17 @@ impl Problem for RosenbrockProblem {
         bounds
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_gradient_matches;
+    use super::*;
+
+    #[test]
+    fn gradient_matches_central_differences() {
+        assert_gradient_matches(
+            &RosenbrockProblem { dim: 3 },
+            &[&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], &[-1.2, 1.0, 0.5], &[2.0, -3.0, 4.5]],
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct RotatedHyperEllipsoid;
+
+impl BenchmarkFn for RotatedHyperEllipsoid {
+    fn name(&self) -> &str {
+        "RotatedHyperEllipsoid"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
+
+    fn success_tolerance(&self) -> f64 {
+        1e-6
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(RotatedHyperEllipsoidProblem { dim }, seed, params, opts)
+    }
+}
+
+fn rotated_hyper_ellipsoid_local(x: &[f64]) -> f64 {
+    // The inner sum over x_1..x_i is a prefix sum, which keeps this O(d)
+    x.iter()
+        .scan(0.0, |prefix, &xi| {
+            *prefix += xi;
+            Some(*prefix * *prefix)
+        })
+        .sum()
+}
+
+#[derive(Clone)]
+struct RotatedHyperEllipsoidProblem {
+    dim: usize,
+}
+
+impl Problem for RotatedHyperEllipsoidProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(rotated_hyper_ellipsoid_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -65.536;
+            bounds[[i, 1]] = 65.536;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn prefix_sum_matches_the_double_loop() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for dim in [1, 2, 5, 30] {
+            let x: Vec<f64> = (0..dim).map(|_| rng.random_range(-65.536..65.536)).collect();
+            let naive: f64 = (0..dim).map(|i| (0..=i).map(|j| x[j]).sum::<f64>().powi(2)).sum();
+            let fast = rotated_hyper_ellipsoid_local(&x);
+            assert!(
+                (fast - naive).abs() <= 1e-9 * naive.max(1.0),
+                "dim {}: {} vs {}",
+                dim,
+                fast,
+                naive
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use super::noise::standard_normal;
+use globalsearch::problem::Problem;
+use globalsearch::types::EvaluationError;
+use ndarray::{Array1, Array2};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::sync::Arc;
+
+/// Base seed of the rotation matrices, so every run at a dimension uses the same rotation.
+const ROTATION_SEED: u64 = 0x524f_5441_5445;
+
+/// Random orthogonal `dim` x `dim` matrix, obtained by modified Gram-Schmidt on a
+/// matrix with standard normal entries. It depends only on `dim`.
+pub fn rotation_matrix(dim: usize) -> Array2<f64> {
+    let mut rng = StdRng::seed_from_u64(ROTATION_SEED.wrapping_add(dim as u64));
+    let mut q = Array2::from_shape_simple_fn((dim, dim), || standard_normal(&mut rng));
+    for i in 0..dim {
+        for j in 0..i {
+            let proj = q.row(i).dot(&q.row(j));
+            let prev = q.row(j).to_owned();
+            q.row_mut(i).scaled_add(-proj, &prev);
+        }
+        let norm = q.row(i).dot(&q.row(i)).sqrt();
+        q.row_mut(i).mapv_inplace(|x| x / norm);
+    }
+    q
+}
+
+/// Wraps a problem and evaluates it at `R x` for a fixed orthogonal matrix `R`,
+/// which makes separable functions non-separable. An optimum `x*` of the wrapped
+/// problem becomes `Rᵀ x*`. The variable bounds are kept, so a rotated optimum
+/// far from the origin can fall outside of them, which fails the run. Without a
+/// matrix the wrapped problem is evaluated unchanged.
+#[derive(Clone)]
+pub struct RotatedProblem<P> {
+    inner: P,
+    rotation: Option<Arc<Array2<f64>>>,
+}
+
+impl<P: Problem> RotatedProblem<P> {
+    pub fn new(inner: P, rotation: Option<Arc<Array2<f64>>>) -> Self {
+        Self { inner, rotation }
+    }
+}
+
+impl<P: Problem> Problem for RotatedProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        match &self.rotation {
+            Some(r) => self.inner.objective(&r.dot(x)),
+            None => self.inner.objective(x),
+        }
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        match &self.rotation {
+            Some(r) => Ok(r.t().dot(&self.inner.gradient(&r.dot(x))?)),
+            None => self.inner.gradient(x),
+        }
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        match &self.rotation {
+            Some(r) => Ok(r.t().dot(&self.inner.hessian(&r.dot(x))?).dot(r.as_ref())),
+            None => self.inner.hessian(x),
+        }
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::shift::ShiftedProblem;
+    use super::super::tests::{Quadratic, plain_options};
+    use super::super::{BenchmarkFn, RunOptions};
+    use super::*;
+
+    #[test]
+    fn rotation_matrix_is_orthogonal() {
+        for dim in [2, 10, 100] {
+            let r = rotation_matrix(dim);
+            let error = (r.t().dot(&r) - Array2::<f64>::eye(dim)).mapv(f64::abs);
+            let max_error = error.iter().copied().fold(0.0, f64::max);
+            assert!(max_error < 1e-10, "dim {}: max |RᵀR - I| = {}", dim, max_error);
+        }
+    }
+
+    #[test]
+    fn rotated_problem_is_minimal_at_the_carried_argmin() {
+        let func = Quadratic::new(&[1.0, -2.0, 0.5]);
+        for shift in [0.0, 0.75] {
+            let opts = RunOptions { rotate: true, shift, ..plain_options() };
+            let argmin = opts.nearest_argmin(&func, 3, &Array1::zeros(3)).unwrap();
+            assert!((&argmin - &func.global_argmin(3).unwrap()).iter().any(|d| d.abs() > 0.1));
+
+            let problem =
+                ShiftedProblem::new(RotatedProblem::new(func.clone(), opts.rotation(3)), shift);
+            let obj = problem.objective(&argmin).unwrap();
+            assert!(
+                (obj - func.global_minimum(3)).abs() < 1e-12,
+                "shift {}: f(x*) = {}",
+                shift,
+                obj
+            );
+        }
+    }
+}
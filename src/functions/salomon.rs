@@ -0,0 +1,75 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Salomon function, rotationally symmetric with ring-shaped local minima around
+/// the origin, where the global minimum 0 lies.
+pub struct Salomon;
+
+impl BenchmarkFn for Salomon {
+    fn name(&self) -> &str {
+        "Salomon"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SalomonProblem { dim }, seed, params, opts)
+    }
+}
+
+fn salomon_local(x: &[f64]) -> f64 {
+    let norm = x.iter().map(|xi| xi.powi(2)).sum::<f64>().sqrt();
+    1.0 - (2.0 * std::f64::consts::PI * norm).cos() + 0.1 * norm
+}
+
+#[derive(Clone)]
+struct SalomonProblem {
+    dim: usize,
+}
+
+impl Problem for SalomonProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(salomon_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = SalomonProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn objective_on_a_whole_radius() {
+        // ||x|| = 5, so the cosine term cancels and only 0.1 * 5 remains
+        let obj = SalomonProblem { dim: 2 }.objective(&Array1::from_vec(vec![3.0, 4.0])).unwrap();
+        assert!((obj - 0.5).abs() < 1e-12, "f(3, 4) = {}", obj);
+    }
+}
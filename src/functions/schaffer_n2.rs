@@ -0,0 +1,66 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::schaffer_n2;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+pub struct SchafferN2;
+
+impl BenchmarkFn for SchafferN2 {
+    fn name(&self) -> &str {
+        "SchafferN2"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        Some(array![0.0, 0.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SchafferN2Problem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct SchafferN2Problem;
+
+impl Problem for SchafferN2Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        // The denominator is at least 1, so the origin evaluates to exactly 0 without a guard
+        Ok(schaffer_n2(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_is_exactly_zero_at_origin() {
+        let obj = SchafferN2Problem.objective(&SchafferN2.global_argmin(2).unwrap()).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+}
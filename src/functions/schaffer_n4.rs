@@ -0,0 +1,86 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use argmin_testfunctions::schaffer_n4;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+/// Distance of the optima from the origin along either axis.
+const OPTIMUM_OFFSET: f64 = 1.253131834;
+
+pub struct SchafferN4;
+
+impl BenchmarkFn for SchafferN4 {
+    fn name(&self) -> &str {
+        "SchafferN4"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![2]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        // The argmin_testfunctions docs list 0.291992, which is not attained at the optimum
+        0.29257863204
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        // One of the four optima at (0, ±c) and (±c, 0)
+        Some(array![0.0, OPTIMUM_OFFSET])
+    }
+
+    fn nearest_argmin(&self, _dim: usize, point: &Array1<f64>) -> Option<Array1<f64>> {
+        // The optimum lies on the axis the point is closest to, on the point's side of the origin
+        if point[0].abs() > point[1].abs() {
+            Some(array![OPTIMUM_OFFSET.copysign(point[0]), 0.0])
+        } else {
+            Some(array![0.0, OPTIMUM_OFFSET.copysign(point[1])])
+        }
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SchafferN4Problem, seed, params, opts)
+    }
+}
+
+#[derive(Clone)]
+struct SchafferN4Problem;
+
+impl Problem for SchafferN4Problem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        let arr = to_2d_array(x)?;
+        Ok(schaffer_n4(&arr))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((2, 2));
+        for i in 0..2 {
+            bounds[[i, 0]] = -100.0;
+            bounds[[i, 1]] = 100.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_each_optimum_matches_minimum() {
+        for point in [array![0.0, 1.0], array![0.0, -1.0], array![1.0, 0.0], array![-1.0, 0.0]] {
+            let argmin = SchafferN4.nearest_argmin(2, &point).unwrap();
+            let obj = SchafferN4Problem.objective(&argmin).unwrap();
+            assert!((obj - SchafferN4.global_minimum(2)).abs() < 1e-9, "f({}) = {}", argmin, obj);
+        }
+    }
+}
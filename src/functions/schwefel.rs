@@ -0,0 +1,71 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct Schwefel;
+
+impl BenchmarkFn for Schwefel {
+    fn name(&self) -> &str {
+        "Schwefel"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "multimodal"]
+    }
+
+    fn success_tolerance(&self) -> f64 {
+        1e-2
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::from_elem(dim, 420.9687))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SchwefelProblem { dim }, seed, params, opts)
+    }
+}
+
+fn schwefel_local(x: &[f64]) -> f64 {
+    let sum = x.iter().map(|&xi| xi * xi.abs().sqrt().sin()).sum::<f64>();
+    418.9829 * x.len() as f64 - sum
+}
+
+#[derive(Clone)]
+struct SchwefelProblem {
+    dim: usize,
+}
+
+impl Problem for SchwefelProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(schwefel_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -500.0;
+            bounds[[i, 1]] = 500.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_near_zero_at_optimum() {
+        let argmin = Schwefel.global_argmin(10).unwrap();
+        let obj = SchwefelProblem { dim: 10 }.objective(&argmin).unwrap();
+        assert!(obj.abs() < Schwefel.success_tolerance(), "f(x*) = {}", obj);
+    }
+}
@@ -0,0 +1,147 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2, array};
+
+/// Centers of the ten maxima (the rows of Cᵀ).
+const C: [[f64; 4]; 10] = [
+    [4.0, 4.0, 4.0, 4.0],
+    [1.0, 1.0, 1.0, 1.0],
+    [8.0, 8.0, 8.0, 8.0],
+    [6.0, 6.0, 6.0, 6.0],
+    [3.0, 7.0, 3.0, 7.0],
+    [2.0, 9.0, 2.0, 9.0],
+    [5.0, 3.0, 5.0, 3.0],
+    [8.0, 1.0, 8.0, 1.0],
+    [6.0, 2.0, 6.0, 2.0],
+    [7.0, 3.6, 7.0, 3.6],
+];
+
+/// Widths of the maxima.
+const BETA: [f64; 10] = [0.1, 0.2, 0.2, 0.4, 0.4, 0.6, 0.3, 0.7, 0.5, 0.5];
+
+/// How many of the ten maxima the Shekel function uses.
+#[derive(Clone, Copy, Default)]
+pub enum ShekelVariant {
+    M5,
+    M7,
+    #[default]
+    M10,
+}
+
+impl ShekelVariant {
+    /// Number of maxima, `m`.
+    pub fn maxima(self) -> usize {
+        match self {
+            ShekelVariant::M5 => 5,
+            ShekelVariant::M7 => 7,
+            ShekelVariant::M10 => 10,
+        }
+    }
+
+    /// Known global minimum for this `m`.
+    pub fn minimum(self) -> f64 {
+        match self {
+            ShekelVariant::M5 => -10.1531996791,
+            ShekelVariant::M7 => -10.4029153367,
+            ShekelVariant::M10 => -10.5364431534,
+        }
+    }
+}
+
+/// Shekel function in 4D using the first `m` maxima.
+#[derive(Default)]
+pub struct Shekel {
+    pub m: ShekelVariant,
+}
+
+impl BenchmarkFn for Shekel {
+    fn name(&self) -> &str {
+        match self.m {
+            ShekelVariant::M5 => "Shekel5",
+            ShekelVariant::M7 => "Shekel7",
+            ShekelVariant::M10 => "Shekel10",
+        }
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
+    fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
+        vec![4]
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        self.m.minimum()
+    }
+
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        // Approximately (4, 4, 4, 4) for every m
+        Some(array![4.0, 4.0, 4.0, 4.0])
+    }
+
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(ShekelProblem { m: self.m }, seed, params, opts)
+    }
+}
+
+fn shekel_local(x: &[f64], m: ShekelVariant) -> f64 {
+    -C.iter()
+        .zip(BETA)
+        .take(m.maxima())
+        .map(|(c, beta)| {
+            let dist: f64 = x.iter().zip(c).map(|(xj, cj)| (xj - cj).powi(2)).sum();
+            1.0 / (dist + beta)
+        })
+        .sum::<f64>()
+}
+
+#[derive(Clone)]
+struct ShekelProblem {
+    m: ShekelVariant,
+}
+
+impl Problem for ShekelProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        if x.len() != 4 {
+            return Err(EvaluationError::InvalidInput { reason: "Expected 4D point".to_string() });
+        }
+        Ok(shekel_local(x.as_slice().unwrap(), self.m))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((4, 2));
+        for i in 0..4 {
+            bounds[[i, 0]] = 0.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_canonical_optimum_matches_each_minimum() {
+        for m in [ShekelVariant::M5, ShekelVariant::M7, ShekelVariant::M10] {
+            let shekel = Shekel { m };
+            let obj = ShekelProblem { m }.objective(&shekel.global_argmin(4).unwrap()).unwrap();
+            // The exact optima lie within 1e-3 of (4, 4, 4, 4)
+            assert!(
+                (obj - shekel.global_minimum(4)).abs() < 1e-3,
+                "{}: f = {}",
+                shekel.name(),
+                obj
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use globalsearch::problem::Problem;
+use globalsearch::types::EvaluationError;
+use ndarray::{Array1, Array2};
+
+/// Wraps a problem and moves its optimum by `delta` along every axis, evaluating
+/// `f(x - delta)`. The variable bounds are kept, so the optimum moves relative to
+/// the search domain rather than along with it.
+#[derive(Clone)]
+pub struct ShiftedProblem<P> {
+    inner: P,
+    delta: f64,
+}
+
+impl<P: Problem> ShiftedProblem<P> {
+    pub fn new(inner: P, delta: f64) -> Self {
+        Self { inner, delta }
+    }
+
+    fn unshift(&self, x: &Array1<f64>) -> Array1<f64> {
+        x.mapv(|xi| xi - self.delta)
+    }
+}
+
+impl<P: Problem> Problem for ShiftedProblem<P> {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        self.inner.objective(&self.unshift(x))
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        self.inner.gradient(&self.unshift(x))
+    }
+
+    fn hessian(&self, x: &Array1<f64>) -> Result<Array2<f64>, EvaluationError> {
+        self.inner.hessian(&self.unshift(x))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        self.inner.variable_bounds()
+    }
+
+    fn constraints(&self) -> Vec<fn(&[f64], &mut ()) -> f64> {
+        self.inner.constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Quadratic;
+    use super::*;
+
+    #[test]
+    fn zero_shift_is_a_no_op() {
+        let base = Quadratic::new(&[1.0, -2.0]);
+        let shifted = ShiftedProblem::new(base.clone(), 0.0);
+        for x in [[0.0, 0.0], [1.0, -2.0], [-3.5, 4.25]] {
+            let x = Array1::from_vec(x.to_vec());
+            assert_eq!(shifted.objective(&x).unwrap(), base.objective(&x).unwrap());
+            assert_eq!(shifted.gradient(&x).unwrap(), base.gradient(&x).unwrap());
+        }
+    }
+
+    #[test]
+    fn shifted_optimum_keeps_the_minimum() {
+        let base = Quadratic::new(&[1.0, -2.0]);
+        let argmin = Array1::from_vec(vec![1.0, -2.0]);
+        let minimum = base.objective(&argmin).unwrap();
+        for delta in [0.5, -1.25, 3.0] {
+            let shifted = ShiftedProblem::new(base.clone(), delta);
+            assert_eq!(shifted.objective(&argmin.mapv(|x| x + delta)).unwrap(), minimum);
+            assert!(shifted.objective(&argmin).unwrap() > minimum);
+        }
+    }
+}
@@ -1,10 +1,8 @@
-use super::{BenchmarkFn, RunResult};
-use globalsearch::observers::Observer;
-use globalsearch::oqnlp::OQNLP;
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp, to_2d_array};
+use crate::objectives::six_hump_camel;
 use globalsearch::problem::Problem;
 use globalsearch::types::{EvaluationError, OQNLPParams};
-use ndarray::{Array1, Array2};
-use std::time::{Duration, Instant};
+use ndarray::{Array1, Array2, array};
 
 pub struct SixHumpCamel;
 
@@ -13,55 +11,32 @@ impl BenchmarkFn for SixHumpCamel {
         "SixHumpCamel"
     }
 
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "multimodal", "low-dim-only"]
+    }
+
     fn supported_dims(&self, _default_dims: &[usize]) -> Vec<usize> {
         vec![2]
     }
 
-    fn run(&self, _dim: usize, seed: u64) -> RunResult {
-        let problem = SixHumpCamelProblem;
-        let params = OQNLPParams { seed, ..OQNLPParams::default() };
-
-        let observer = Observer::new().with_stage1_tracking().with_stage2_tracking().with_timing();
-        let mut optimizer =
-            OQNLP::new(problem, params).expect("Failed to create OQNLP").add_observer(observer);
-
-        let start = Instant::now();
-        let solution_set = std::hint::black_box(optimizer.run()).expect("OQNLP run failed");
-        let duration = start.elapsed();
-
-        let obs = optimizer.observer().unwrap();
-        let stage1_duration = obs
-            .stage1_final()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
-        let stage2_duration = obs
-            .stage2()
-            .and_then(|s| s.total_time())
-            .map(Duration::from_secs_f64)
-            .unwrap_or(Duration::ZERO);
-
-        let best_sol = solution_set.best_solution().expect("No solutions found");
-        let obj = best_sol.objective;
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        -1.0316
+    }
 
-        // Global min is -1.0316
-        RunResult {
-            success: (obj - (-1.0316)).abs() < 1e-4,
-            runtime: duration,
-            stage1_runtime: stage1_duration,
-            stage2_runtime: stage2_duration,
-            best_obj: obj,
-            solution_set_size: solution_set.len(),
-        }
+    fn global_argmin(&self, _dim: usize) -> Option<Array1<f64>> {
+        // One of the two symmetric optima
+        Some(array![0.0898, -0.7126])
     }
-}
 
-fn six_hump_camel_local(x: &[f64]) -> f64 {
-    let x1 = x[0];
-    let x2 = x[1];
-    (4.0 - 2.1 * x1.powi(2) + x1.powi(6) / 3.0) * x1.powi(2)
-        + x1 * x2
-        + (-4.0 + 4.0 * x2.powi(2)) * x2.powi(2)
+    fn run(
+        &self,
+        _dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SixHumpCamelProblem, seed, params, opts)
+    }
 }
 
 #[derive(Clone)]
@@ -69,10 +44,8 @@ struct SixHumpCamelProblem;
 
 impl Problem for SixHumpCamelProblem {
     fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
-        let arr: [f64; 2] = x.as_slice().and_then(|s| s.try_into().ok()).ok_or_else(|| {
-            EvaluationError::InvalidInput { reason: "Expected 2D point".to_string() }
-        })?;
-        Ok(six_hump_camel_local(&arr))
+        let arr = to_2d_array(x)?;
+        Ok(six_hump_camel(&arr))
     }
 
     fn variable_bounds(&self) -> Array2<f64> {
@@ -84,3 +57,14 @@ impl Problem for SixHumpCamelProblem {
         bounds
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_optimum_is_global_minimum() {
+        let obj = SixHumpCamelProblem.objective(&array![0.0898, -0.7126]).unwrap();
+        assert!((obj - SixHumpCamel.global_minimum(2)).abs() < 1e-4, "f(x*) = {}", obj);
+    }
+}
@@ -0,0 +1,91 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct Sphere;
+
+impl BenchmarkFn for Sphere {
+    fn name(&self) -> &str {
+        "Sphere"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "unimodal"]
+    }
+
+    fn success_tolerance(&self) -> f64 {
+        1e-8
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn has_gradient(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SphereProblem { dim }, seed, params, opts)
+    }
+}
+
+fn sphere_local(x: &[f64]) -> f64 {
+    x.iter().map(|&xi| xi.powi(2)).sum()
+}
+
+#[derive(Clone)]
+struct SphereProblem {
+    dim: usize,
+}
+
+impl Problem for SphereProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(sphere_local(x.as_slice().unwrap()))
+    }
+
+    fn gradient(&self, x: &Array1<f64>) -> Result<Array1<f64>, EvaluationError> {
+        Ok(x.mapv(|xi| 2.0 * xi))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -5.12;
+            bounds[[i, 1]] = 5.12;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_gradient_matches;
+    use super::*;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = SphereProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn supports_default_dims() {
+        assert_eq!(Sphere.supported_dims(&[10, 50, 100]), vec![10, 50, 100]);
+    }
+
+    #[test]
+    fn gradient_matches_central_differences() {
+        assert_gradient_matches(
+            &SphereProblem { dim: 3 },
+            &[&[0.0, 0.0, 0.0], &[1.0, -2.0, 3.0], &[-5.12, 0.5, 4.0]],
+        );
+    }
+}
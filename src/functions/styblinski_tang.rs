@@ -0,0 +1,76 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+/// Minimum value contributed by each coordinate, attained at `x_i = -2.903534`.
+const MIN_PER_DIM: f64 = -39.16616570377141;
+
+pub struct StyblinskiTang;
+
+impl BenchmarkFn for StyblinskiTang {
+    fn name(&self) -> &str {
+        "StyblinskiTang"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "multimodal"]
+    }
+
+    // The global minimum scales linearly with the dimension
+    fn global_minimum(&self, dim: usize) -> f64 {
+        MIN_PER_DIM * dim as f64
+    }
+
+    fn is_success(&self, dim: usize, obj: f64, tol: f64) -> bool {
+        (obj - self.global_minimum(dim)).abs() < tol * dim as f64
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::from_elem(dim, -2.903534))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(StyblinskiTangProblem { dim }, seed, params, opts)
+    }
+}
+
+pub fn styblinski_tang_local(x: &[f64]) -> f64 {
+    0.5 * x.iter().map(|&xi| xi.powi(4) - 16.0 * xi.powi(2) + 5.0 * xi).sum::<f64>()
+}
+
+#[derive(Clone)]
+struct StyblinskiTangProblem {
+    dim: usize,
+}
+
+impl Problem for StyblinskiTangProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(styblinski_tang_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -5.0;
+            bounds[[i, 1]] = 5.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimum_scales_with_dim() {
+        assert!((StyblinskiTang.global_minimum(10) + 391.66).abs() < 1e-2);
+    }
+}
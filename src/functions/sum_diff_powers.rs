@@ -0,0 +1,74 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct SumDiffPowers;
+
+impl BenchmarkFn for SumDiffPowers {
+    fn name(&self) -> &str {
+        "SumDiffPowers"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "unimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SumDiffPowersProblem { dim }, seed, params, opts)
+    }
+}
+
+fn sum_diff_powers_local(x: &[f64]) -> f64 {
+    // Exponents grow with the (1-based) index, flattening the basin near the origin
+    x.iter().enumerate().map(|(i, &xi)| xi.abs().powi(i as i32 + 2)).sum()
+}
+
+#[derive(Clone)]
+struct SumDiffPowersProblem {
+    dim: usize,
+}
+
+impl Problem for SumDiffPowersProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(sum_diff_powers_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -1.0;
+            bounds[[i, 1]] = 1.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = SumDiffPowersProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn objective_raises_coordinates_to_growing_powers() {
+        // 0.5^2 + 0.5^3 + 1^4
+        let obj = SumDiffPowersProblem { dim: 3 }.objective(&array![0.5, -0.5, 1.0]).unwrap();
+        assert!((obj - 1.375).abs() < 1e-12, "f = {}", obj);
+    }
+}
@@ -0,0 +1,73 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct SumSquares;
+
+impl BenchmarkFn for SumSquares {
+    fn name(&self) -> &str {
+        "SumSquares"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["separable", "unimodal"]
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(SumSquaresProblem { dim }, seed, params, opts)
+    }
+}
+
+fn sum_squares_local(x: &[f64]) -> f64 {
+    x.iter().enumerate().map(|(i, &xi)| (i + 1) as f64 * xi.powi(2)).sum()
+}
+
+#[derive(Clone)]
+struct SumSquaresProblem {
+    dim: usize,
+}
+
+impl Problem for SumSquaresProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(sum_squares_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -10.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = SumSquaresProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn objective_weights_coordinates_by_index() {
+        // 1 * 1 + 2 * 4 + 3 * 0.25
+        let obj = SumSquaresProblem { dim: 3 }.objective(&array![1.0, -2.0, 0.5]).unwrap();
+        assert!((obj - 9.75).abs() < 1e-12, "f = {}", obj);
+    }
+}
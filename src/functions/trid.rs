@@ -0,0 +1,82 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct Trid;
+
+impl BenchmarkFn for Trid {
+    fn name(&self) -> &str {
+        "Trid"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
+
+    fn global_minimum(&self, dim: usize) -> f64 {
+        let d = dim as f64;
+        -d * (d + 4.0) * (d - 1.0) / 6.0
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        // x_i = i * (d + 1 - i), with 1-based indices
+        Some(Array1::from_shape_fn(dim, |i| ((i + 1) * (dim - i)) as f64))
+    }
+
+    fn is_success(&self, dim: usize, obj: f64, tol: f64) -> bool {
+        // The optimum grows like d^3, so the tolerance is relative to its magnitude
+        let min = self.global_minimum(dim);
+        (obj - min).abs() < tol * min.abs().max(1.0)
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(TridProblem { dim }, seed, params, opts)
+    }
+}
+
+fn trid_local(x: &[f64]) -> f64 {
+    let squares: f64 = x.iter().map(|&xi| (xi - 1.0).powi(2)).sum();
+    let coupling: f64 = x.windows(2).map(|w| w[1] * w[0]).sum();
+    squares - coupling
+}
+
+#[derive(Clone)]
+struct TridProblem {
+    dim: usize,
+}
+
+impl Problem for TridProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(trid_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        // Bounds grow with the dimension, like the optimum
+        let limit = (self.dim * self.dim) as f64;
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -limit;
+            bounds[[i, 1]] = limit;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_form_minimum_for_10d() {
+        assert_eq!(Trid.global_minimum(10), -210.0);
+        let obj = TridProblem { dim: 10 }.objective(&Trid.global_argmin(10).unwrap()).unwrap();
+        assert!((obj + 210.0).abs() < 1e-9, "f(x*) = {}", obj);
+    }
+}
@@ -0,0 +1,153 @@
+use super::noise::point_rng;
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use clap::ValueEnum;
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+use rand::Rng;
+use std::f64::consts::PI;
+
+/// Which of the four Xin-She Yang functions to benchmark.
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum XinSheYangVariant {
+    /// `sum eps_i |x_i|^i` with `eps_i` uniform in [0, 1), drawn from the point and the run seed
+    N1,
+    /// `sum |x_i| * exp(-sum sin(x_i^2))`
+    #[default]
+    N2,
+    /// `exp(-sum (x_i / 15)^10) - 2 exp(-sum x_i^2) prod cos^2(x_i)`
+    N3,
+    /// `(sum sin^2(x_i) - exp(-sum x_i^2)) * exp(-sum sin^2(sqrt|x_i|))`
+    N4,
+}
+
+/// Xin-She Yang functions, all with their global minimum at the origin.
+pub struct XinSheYang {
+    pub variant: XinSheYangVariant,
+}
+
+impl BenchmarkFn for XinSheYang {
+    fn name(&self) -> &str {
+        match self.variant {
+            XinSheYangVariant::N1 => "XinSheYangN1",
+            XinSheYangVariant::N2 => "XinSheYangN2",
+            XinSheYangVariant::N3 => "XinSheYangN3",
+            XinSheYangVariant::N4 => "XinSheYangN4",
+        }
+    }
+
+    fn tags(&self) -> &[&str] {
+        match self.variant {
+            XinSheYangVariant::N1 => &["separable", "multimodal"],
+            _ => &["non-separable", "multimodal"],
+        }
+    }
+
+    fn global_minimum(&self, _dim: usize) -> f64 {
+        match self.variant {
+            XinSheYangVariant::N1 | XinSheYangVariant::N2 => 0.0,
+            XinSheYangVariant::N3 | XinSheYangVariant::N4 => -1.0,
+        }
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(XinSheYangProblem { dim, variant: self.variant, seed }, seed, params, opts)
+    }
+}
+
+fn xin_she_yang_local(x: &[f64], variant: XinSheYangVariant, seed: u64) -> f64 {
+    let sum_sq: f64 = x.iter().map(|xi| xi.powi(2)).sum();
+    match variant {
+        XinSheYangVariant::N1 => {
+            // Drawn from the point and the run seed rather than a shared generator, so
+            // runs are reproducible under parallel evaluation
+            let mut rng = point_rng(seed, x);
+            x.iter()
+                .enumerate()
+                .map(|(i, &xi)| rng.random::<f64>() * xi.abs().powi(i as i32 + 1))
+                .sum()
+        }
+        XinSheYangVariant::N2 => {
+            let abs_sum: f64 = x.iter().map(|xi| xi.abs()).sum();
+            abs_sum * (-x.iter().map(|xi| xi.powi(2).sin()).sum::<f64>()).exp()
+        }
+        XinSheYangVariant::N3 => {
+            let flat = (-x.iter().map(|xi| (xi / 15.0).powi(10)).sum::<f64>()).exp();
+            let well = x.iter().map(|xi| xi.cos().powi(2)).product::<f64>();
+            flat - 2.0 * (-sum_sq).exp() * well
+        }
+        XinSheYangVariant::N4 => {
+            let sin_sq: f64 = x.iter().map(|xi| xi.sin().powi(2)).sum();
+            let envelope: f64 = x.iter().map(|xi| xi.abs().sqrt().sin().powi(2)).sum();
+            (sin_sq - (-sum_sq).exp()) * (-envelope).exp()
+        }
+    }
+}
+
+#[derive(Clone)]
+struct XinSheYangProblem {
+    dim: usize,
+    variant: XinSheYangVariant,
+    // Run seed, from which N.1 draws its weights
+    seed: u64,
+}
+
+impl Problem for XinSheYangProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(xin_she_yang_local(x.as_slice().unwrap(), self.variant, self.seed))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let limit = match self.variant {
+            XinSheYangVariant::N1 => 5.0,
+            XinSheYangVariant::N2 => 2.0 * PI,
+            XinSheYangVariant::N3 => 20.0,
+            XinSheYangVariant::N4 => 10.0,
+        };
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -limit;
+            bounds[[i, 1]] = limit;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_at_origin_is_global_minimum() {
+        for variant in [XinSheYangVariant::N2, XinSheYangVariant::N3, XinSheYangVariant::N4] {
+            let obj = xin_she_yang_local(&[0.0; 5], variant, 0);
+            let expected = XinSheYang { variant }.global_minimum(5);
+            assert!(
+                (obj - expected).abs() < 1e-12,
+                "{}: f(0) = {}",
+                XinSheYang { variant }.name(),
+                obj
+            );
+        }
+    }
+
+    #[test]
+    fn n1_weights_depend_only_on_seed_and_point() {
+        let x = [0.5, -1.5, 2.0];
+        let obj = xin_she_yang_local(&x, XinSheYangVariant::N1, 7);
+        // Evaluating another point in between doesn't shift the draws
+        xin_she_yang_local(&[1.0; 3], XinSheYangVariant::N1, 7);
+        assert_eq!(xin_she_yang_local(&x, XinSheYangVariant::N1, 7), obj);
+        assert_ne!(xin_she_yang_local(&x, XinSheYangVariant::N1, 8), obj);
+    }
+}
@@ -0,0 +1,80 @@
+use super::{BenchError, BenchmarkFn, RunOptions, RunResult, run_oqnlp};
+use globalsearch::problem::Problem;
+use globalsearch::types::{EvaluationError, OQNLPParams};
+use ndarray::{Array1, Array2};
+
+pub struct Zakharov;
+
+impl BenchmarkFn for Zakharov {
+    fn name(&self) -> &str {
+        "Zakharov"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["non-separable", "unimodal"]
+    }
+
+    fn success_tolerance(&self) -> f64 {
+        1e-6
+    }
+
+    fn global_argmin(&self, dim: usize) -> Option<Array1<f64>> {
+        Some(Array1::zeros(dim))
+    }
+
+    fn run(
+        &self,
+        dim: usize,
+        seed: u64,
+        params: &OQNLPParams,
+        opts: &RunOptions,
+    ) -> Result<RunResult, BenchError> {
+        run_oqnlp(ZakharovProblem { dim }, seed, params, opts)
+    }
+}
+
+fn zakharov_local(x: &[f64]) -> f64 {
+    let sum_sq: f64 = x.iter().map(|&xi| xi.powi(2)).sum();
+    // Coupling term, with 1-based indices
+    let weighted: f64 = x.iter().enumerate().map(|(i, &xi)| 0.5 * (i + 1) as f64 * xi).sum();
+    sum_sq + weighted.powi(2) + weighted.powi(4)
+}
+
+#[derive(Clone)]
+struct ZakharovProblem {
+    dim: usize,
+}
+
+impl Problem for ZakharovProblem {
+    fn objective(&self, x: &Array1<f64>) -> Result<f64, EvaluationError> {
+        Ok(zakharov_local(x.as_slice().unwrap()))
+    }
+
+    fn variable_bounds(&self) -> Array2<f64> {
+        let mut bounds = Array2::zeros((self.dim, 2));
+        for i in 0..self.dim {
+            bounds[[i, 0]] = -5.0;
+            bounds[[i, 1]] = 10.0;
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn objective_is_zero_at_origin() {
+        let obj = ZakharovProblem { dim: 10 }.objective(&Array1::zeros(10)).unwrap();
+        assert_eq!(obj, 0.0);
+    }
+
+    #[test]
+    fn objective_includes_coupling_terms() {
+        // 2 + 1.5^2 + 1.5^4
+        let obj = ZakharovProblem { dim: 2 }.objective(&array![1.0, 1.0]).unwrap();
+        assert!((obj - 9.3125).abs() < 1e-12, "f(1, 1) = {}", obj);
+    }
+}
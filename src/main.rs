@@ -1,318 +1,4162 @@
-use clap::Parser;
+use clap::builder::{PossibleValuesParser, RangedU64ValueParser};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use config::Config;
 use functions::{
-    BenchmarkFn, ackley::Ackley, cross_in_tray::CrossInTray, griewank::Griewank, levy::Levy,
-    rastrigin::Rastrigin, rosenbrock::Rosenbrock, six_hump_camel::SixHumpCamel,
+    BenchError, BenchmarkFn, RunOptions, RunResult, SuccessMetric, TAGS, WithGradients,
+    ackley::Ackley,
+    ackley_n2::AckleyN2,
+    ackley_n4::AckleyN4,
+    alpine_n1::AlpineN1,
+    beale::Beale,
+    bohachevsky::{Bohachevsky, BohachevskyVariant},
+    booth::Booth,
+    branin::Branin,
+    bukin_n6::BukinN6,
+    chung_reynolds::ChungReynolds,
+    cross_in_tray::CrossInTray,
+    dixon_price::DixonPrice,
+    drop_wave::DropWave,
+    easom::Easom,
+    eggholder::Eggholder,
+    goldstein_price::GoldsteinPrice,
+    griewank::Griewank,
+    holder_table::HolderTable,
+    langermann::Langermann,
+    levy::Levy,
+    levy_n13::LevyN13,
+    matyas::Matyas,
+    mccormick::McCormick,
+    michalewicz::Michalewicz,
+    perm0::Perm0,
+    permdb::PermDB,
+    qing::Qing,
+    rastrigin::Rastrigin,
+    rosenbrock::Rosenbrock,
+    rotated_hyper_ellipsoid::RotatedHyperEllipsoid,
+    salomon::Salomon,
+    schaffer_n2::SchafferN2,
+    schaffer_n4::SchafferN4,
+    schwefel::Schwefel,
+    shekel::{Shekel, ShekelVariant},
+    six_hump_camel::SixHumpCamel,
+    sphere::Sphere,
+    styblinski_tang::StyblinskiTang,
+    sum_diff_powers::SumDiffPowers,
+    sum_squares::SumSquares,
+    three_hump_camel::ThreeHumpCamel,
+    trid::Trid,
+    xin_she_yang::{XinSheYang, XinSheYangVariant},
+    zakharov::Zakharov,
 };
-use plotly::common::{ErrorData, ErrorType, Mode, Title, Visible};
-use plotly::layout::{Axis, GridPattern, Layout, LayoutGrid};
-use plotly::{Plot, Scatter};
+use globalsearch::types::OQNLPParams;
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use ndarray::Array1;
+use plot_output::{PlotFormat, write_plot};
+use plotly::box_plot::BoxPoints;
+use plotly::common::color::NamedColor;
+use plotly::common::{
+    DashType, ErrorData, ErrorType, Line, LineShape, Marker, Mode, Title, Visible,
+};
+use plotly::histogram::Bins;
+use plotly::layout::{Axis, AxisType, BarMode, GridPattern, Layout, LayoutGrid};
+use plotly::{Bar, BoxPlot, Histogram, Plot, Scatter, Trace};
+use rayon::prelude::*;
+use score::{ScoreWeights, leaderboard, overall_score, parse_score_weights};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use stats::{iqr_outliers, trimmed_mean, ttest, wilson_interval};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufReader, IsTerminal};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+mod config;
 mod functions;
+mod objectives;
+mod plot_output;
+mod score;
+mod stats;
+
+// Significance level for the baseline runtime t-test
+const ALPHA: f64 = 0.05;
+
+// Run `i` uses seed `i * SEED_STEP`
+const SEED_STEP: u64 = 702983;
+
+// Version of the saved results format; bump it whenever `StatPoint` or `AllStats` gain fields
+const SCHEMA_VERSION: u32 = 7;
+
+// `StatPoint` metrics added after version 1, which older files may lack. `avg_convergence` is left out
+// because it is only written with --plot-convergence
+const ADDED_METRICS: [&str; 17] = [
+    "success_rate_ci_low",
+    "success_rate_ci_high",
+    "ci95_runtime_sec",
+    "trimmed_mean_runtime_sec",
+    "runtime_outliers",
+    "avg_cpu_sec",
+    "avg_peak_memory_bytes",
+    "stage1_fraction",
+    "stage2_fraction",
+    "avg_evaluations",
+    "failed_runs",
+    "timeouts",
+    "runs_used",
+    "avg_solution_distance",
+    "avg_near_optimal",
+    "avg_min_pairwise_distance",
+    "raw_runtimes",
+];
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    /// TOML file with benchmark settings; flags given on the command line take precedence
+    #[arg(long)]
+    config: Option<String>,
+
     /// Benchmark function to run (all if not specified)
     #[arg(short, long)]
     function: Option<String>,
 
+    /// Comma-separated benchmark functions to run, e.g. `sphere,rastrigin`
+    #[arg(long, value_delimiter = ',', conflicts_with = "function")]
+    functions: Option<Vec<String>>,
+
+    /// Only run the functions carrying this tag (combined with `--function(s)` if given)
+    #[arg(long, value_parser = PossibleValuesParser::new(TAGS))]
+    tag: Option<String>,
+
     /// Specific dimension to run (runs default set 10, 50, 100 if not specified)
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "dims")]
     dim: Option<usize>,
 
+    /// Comma-separated dimensions and ranges, e.g. `2,5,10-20:5` (start-end:step)
+    #[arg(long, value_parser = parse_dims)]
+    dims: Option<DimList>,
+
+    /// OQNLP population size (library default if not specified)
+    #[arg(long)]
+    population_size: Option<usize>,
+
+    /// OQNLP stage-2 iterations (library default if not specified)
+    #[arg(long)]
+    iterations: Option<usize>,
+
+    /// OQNLP wait cycle (library default if not specified)
+    #[arg(long)]
+    wait_cycle: Option<usize>,
+
+    /// OQNLP merit filter threshold factor (library default if not specified)
+    #[arg(long)]
+    threshold_factor: Option<f64>,
+
+    /// OQNLP distance factor (library default if not specified)
+    #[arg(long)]
+    distance_factor: Option<f64>,
+
     /// Number of runs per dimension
-    #[arg(short, long, default_value_t = 20)]
+    #[arg(short, long, default_value_t = 20, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     runs: usize,
 
+    /// Keep adding runs until the 95% CI half-width of the success rate is below this
+    /// (--runs becomes the minimum number of runs)
+    #[arg(long, conflicts_with = "seeds")]
+    ci_target: Option<f64>,
+
+    /// Maximum number of runs per dimension with --ci-target
+    #[arg(long, default_value_t = 200)]
+    max_runs: usize,
+
+    /// File with newline- or comma-separated seeds to use instead of the generated ones (overrides --runs)
+    #[arg(long)]
+    seeds: Option<String>,
+
+    /// Discarded runs per function and dimension before the measured ones
+    #[arg(long, default_value_t = 0)]
+    warmup: usize,
+
+    /// Let warmup runs consume the first generated seeds instead of reusing the measured ones
+    #[arg(long, conflicts_with = "seeds")]
+    warmup_advances_seeds: bool,
+
+    /// Fold the dimension into each seed so runs at different dimensions are decorrelated
+    #[arg(long)]
+    seed_mixes_dim: bool,
+
+    /// Abandon individual runs that take longer than this many seconds
+    #[arg(long)]
+    timeout_sec: Option<f64>,
+
+    /// Stop stage 2 of every run after this many iterations (capped at the configured iterations)
+    #[arg(long)]
+    budget_iters: Option<usize>,
+
+    /// Let OQNLP stop stage 2 of every run once it has taken this many seconds
+    #[arg(long)]
+    budget_sec: Option<f64>,
+
+    /// Print every function with its dimensions and global minima (tab-separated), then exit
+    #[arg(long)]
+    list_functions: bool,
+
+    /// Print the functions, dimensions, seeds and parameters that would run, then exit
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Also run every function with an analytic gradient using the L-BFGS local solver
+    #[arg(long)]
+    use_gradients: bool,
+
+    /// Standard deviation of Gaussian noise added to every objective evaluation
+    #[arg(long, default_value_t = 0.0)]
+    noise_sigma: f64,
+
+    /// Move every function's optimum by this offset along each axis, keeping the bounds
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    shift: f64,
+
+    /// Evaluate every function in a fixed, randomly rotated coordinate system
+    #[arg(long)]
+    rotate: bool,
+
+    /// Scale the width of every function's bounds by this factor around their center
+    #[arg(long, default_value_t = 1.0)]
+    bounds_scale: f64,
+
+    /// Which Xin-She Yang function to benchmark (N.1 is stochastic)
+    #[arg(long, value_enum, default_value_t = XinSheYangVariant::default())]
+    variant: XinSheYangVariant,
+
+    /// Success tolerance applied to every function (per-function default if not specified)
+    #[arg(long, value_parser = parse_tolerance)]
+    tolerance: Option<f64>,
+
+    /// Whether the success tolerance bounds the absolute or the relative error of the best objective
+    #[arg(long, value_enum, default_value_t = SuccessMetric::Absolute)]
+    success_metric: SuccessMetric,
+
+    /// Benchmark the selected functions concurrently
+    #[arg(long)]
+    parallel_functions: bool,
+
+    /// Hide the progress bar shown when stdout is a terminal
+    #[arg(long)]
+    quiet: bool,
+
+    /// Retry runs that return no usable solution up to this many times with a perturbed seed
+    #[arg(long, default_value_t = 0)]
+    retry: u64,
+
+    /// Abort the whole benchmark on the first failed run
+    #[arg(long, conflicts_with = "keep_going")]
+    fail_fast: bool,
+
+    /// Keep going after failed runs and tally them in the report (default)
+    #[arg(long)]
+    keep_going: bool,
+
+    /// Plot the averaged stage-2 convergence curve per dimension
+    #[arg(long)]
+    plot_convergence: bool,
+
+    /// Plot the per-run runtime distribution per dimension as box plots
+    #[arg(long)]
+    boxplots: bool,
+
+    /// Plot a histogram of the per-run best objective, one subplot per dimension
+    #[arg(long)]
+    obj_histogram: bool,
+
+    /// Plot the per-run best objective as violins, one per dimension
+    #[arg(long)]
+    obj_violin: bool,
+
+    /// Plot the fraction of successful runs within each time budget, per dimension
+    #[arg(long)]
+    performance_profile: bool,
+
+    /// Plot success rate vs dimension for all functions on shared axes
+    #[arg(long)]
+    overview: bool,
+
+    /// Plot the success rate of every function at every dimension as a heatmap
+    #[arg(long)]
+    heatmap: bool,
+
+    /// Report and plot every runtime as a multiple of this function's runtime at the same
+    /// dimension, e.g. `sphere`
+    #[arg(long)]
+    normalize_to: Option<String>,
+
+    /// Skip generating plots; results are still saved and printed
+    #[arg(long)]
+    no_plots: bool,
+
+    /// Output format of the generated plots (png/svg require the `kaleido` feature)
+    #[arg(long, value_enum, default_value_t = PlotFormat::Html)]
+    plot_format: PlotFormat,
+
+    /// Runtime measure to report and plot
+    #[arg(long, value_enum, default_value_t = Timing::Wall)]
+    timing: Timing,
+
+    /// Fraction of the fastest and of the slowest runs left out of the trimmed mean runtime
+    #[arg(long, default_value_t = 0.1)]
+    trim_fraction: f64,
+
+    /// Overlay a reference runtime curve, anchored at the smallest dimension, on the runtime plot
+    #[arg(long, value_enum)]
+    reference_curve: Option<ReferenceCurve>,
+
+    /// Exponents of the success rate and the speed in the leaderboard score, e.g. `sr=0.7,time=0.3`
+    #[arg(long, value_parser = parse_score_weights, default_value = "sr=1,time=1")]
+    score_weights: ScoreWeights,
+
+    /// Statistic used for the runtime error bars
+    #[arg(long, value_enum, default_value_t = ErrorBars::Std)]
+    error_bars: ErrorBars,
+
     /// Save current stats to a JSON file
     #[arg(long)]
     save_json: Option<String>,
 
-    /// Load baseline stats from a JSON file to compare against
+    /// Merge current stats into a JSON file, creating it if missing
+    #[arg(long)]
+    append_json: Option<String>,
+
+    /// How --append-json handles functions already present in the file
+    #[arg(long, value_enum, default_value_t = OnConflict::Replace, requires = "append_json")]
+    on_conflict: OnConflict,
+
+    /// Dump every completed run, keyed by function and dimension, to a JSON file
+    #[arg(long)]
+    dump_raw: Option<String>,
+
+    /// Include the best point of every run in the --dump-raw file
+    #[arg(long)]
+    dump_points: bool,
+
+    /// Recompute the stats and plots from a --dump-raw file instead of running OQNLP
+    #[arg(long, conflicts_with = "dry_run")]
+    from_raw: Option<String>,
+
+    /// Rerun the selected functions once per value of an OQNLP parameter and overlay the
+    /// results, e.g. `population_size=10,20,40` (`population_size` or `iterations`)
+    #[arg(
+        long,
+        value_parser = parse_sweep,
+        conflicts_with_all = ["from_raw", "checkpoint", "dry_run"]
+    )]
+    sweep_param: Option<Sweep>,
+
+    /// Rerun only the unsuccessful runs of a --dump-raw file, with OQNLP's verbose output
+    #[arg(long, conflicts_with_all = ["from_raw", "checkpoint", "dry_run"])]
+    only_failures: Option<String>,
+
+    /// Save progress to this file after every function and dimension, and resume from it if it exists
+    #[arg(long, conflicts_with = "from_raw")]
+    checkpoint: Option<String>,
+
+    /// Save current stats to a CSV file (one row per function and dimension)
+    #[arg(long)]
+    save_csv: Option<String>,
+
+    /// Write a Markdown summary table per function (with deltas if a baseline is loaded)
+    #[arg(long)]
+    markdown: Option<String>,
+
+    /// Export every run as a flat CSV table for comparison with other solvers
     #[arg(long)]
-    load_baseline: Option<String>,
+    export_table: Option<String>,
+
+    /// Upgrade a results file saved by an older version to the current format, then exit
+    #[arg(long, num_args = 2, value_names = ["IN", "OUT"])]
+    migrate_baseline: Option<Vec<String>>,
+
+    /// Load baseline stats from a JSON file to compare against (repeatable)
+    #[arg(long)]
+    load_baseline: Vec<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Timing {
+    /// Elapsed wall-clock time
+    Wall,
+    /// CPU time of the optimizing thread
+    Cpu,
+    /// Both wall-clock and CPU time
+    Both,
+}
+
+impl Timing {
+    fn wall(self) -> bool {
+        matches!(self, Timing::Wall | Timing::Both)
+    }
+
+    fn cpu(self) -> bool {
+        matches!(self, Timing::Cpu | Timing::Both)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ErrorBars {
+    /// Standard deviation of the runs
+    Std,
+    /// 95% confidence interval of the mean
+    Ci,
+    /// Standard error of the mean
+    Sem,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReferenceCurve {
+    /// O(d)
+    Linear,
+    /// O(d²)
+    Quadratic,
+    /// O(d³)
+    Cubic,
+}
+
+impl ReferenceCurve {
+    fn exponent(self) -> i32 {
+        match self {
+            ReferenceCurve::Linear => 1,
+            ReferenceCurve::Quadratic => 2,
+            ReferenceCurve::Cubic => 3,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ReferenceCurve::Linear => "O(d)",
+            ReferenceCurve::Quadratic => "O(d²)",
+            ReferenceCurve::Cubic => "O(d³)",
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OnConflict {
+    /// Overwrite the stored results with the current ones
+    Replace,
+    /// Abort without writing anything
+    Error,
+}
+
+impl Cli {
+    /// Parses the command line and fills every setting not given there from `--config`.
+    fn parse_with_config() -> Self {
+        let matches = Cli::command().get_matches();
+        let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+        if let Some(path) = cli.config.clone() {
+            let config = config::load(&path).unwrap_or_else(|e| {
+                Cli::command().error(ErrorKind::InvalidValue, format!("{}: {}", path, e)).exit()
+            });
+            cli.apply_config(config, &matches).unwrap_or_else(|e| {
+                Cli::command().error(ErrorKind::InvalidValue, format!("{}: {}", path, e)).exit()
+            });
+        }
+        cli
+    }
+
+    /// Fills the settings not given on the command line from `config`, whose
+    /// values must pass the same checks as the corresponding flags.
+    fn apply_config(&mut self, config: Config, matches: &ArgMatches) -> Result<(), String> {
+        let params = config.params;
+        let output = config.output;
+        if self.functions.is_none() {
+            self.function = self.function.take().or(config.function);
+        }
+        if self.dim.is_none() && self.dims.is_none() {
+            self.dims = config.dims.map(dim_list).transpose()?;
+        }
+        if matches.value_source("runs") != Some(ValueSource::CommandLine)
+            && let Some(runs) = config.runs
+        {
+            if runs == 0 {
+                return Err("runs must be positive".to_string());
+            }
+            self.runs = runs;
+        }
+        if self.tolerance.is_none() {
+            self.tolerance = config.tolerance.map(check_tolerance).transpose()?;
+        }
+        self.population_size = self.population_size.or(params.population_size);
+        self.iterations = self.iterations.or(params.iterations);
+        self.wait_cycle = self.wait_cycle.or(params.wait_cycle);
+        self.threshold_factor = self.threshold_factor.or(params.threshold_factor);
+        self.distance_factor = self.distance_factor.or(params.distance_factor);
+        self.save_json = self.save_json.take().or(output.save_json);
+        self.save_csv = self.save_csv.take().or(output.save_csv);
+        self.export_table = self.export_table.take().or(output.export_table);
+        self.markdown = self.markdown.take().or(output.markdown);
+        self.dump_raw = self.dump_raw.take().or(output.dump_raw);
+        Ok(())
+    }
+
+    /// Seed actually passed to OQNLP for a run with base seed `seed` at `dim`.
+    fn run_seed(&self, seed: u64, dim: usize) -> u64 {
+        if self.seed_mixes_dim { mix_seed(seed, dim as u64) } else { seed }
+    }
+
+    /// Whether a run failing with `e` aborts the whole benchmark. Under --keep-going
+    /// only a transformation that lost the optimum does, since every run would hit it.
+    fn aborts_on(&self, e: &BenchError) -> bool {
+        self.fail_fast || matches!(e, BenchError::OptimumOutOfBounds)
+    }
+
+    /// Whether --ci-target is met after `trials` runs with `successes` among them,
+    /// never before the --runs minimum.
+    fn ci_target_met(&self, successes: usize, trials: usize) -> bool {
+        self.ci_target.is_some_and(|target| {
+            trials >= self.runs && success_ci_half_width(successes, trials) < target
+        })
+    }
+
+    /// Number of seeds to prepare; adaptive runs draw from a pool of `max_runs`.
+    fn seed_count(&self) -> usize {
+        if self.ci_target.is_some() { self.max_runs } else { self.runs }
+    }
+
+    /// Library default OQNLP parameters with the CLI overrides applied.
+    fn oqnlp_params(&self) -> OQNLPParams {
+        let defaults = OQNLPParams::default();
+        OQNLPParams {
+            population_size: self.population_size.unwrap_or(defaults.population_size),
+            iterations: self.iterations.unwrap_or(defaults.iterations),
+            wait_cycle: self.wait_cycle.unwrap_or(defaults.wait_cycle),
+            threshold_factor: self.threshold_factor.unwrap_or(defaults.threshold_factor),
+            distance_factor: self.distance_factor.unwrap_or(defaults.distance_factor),
+            ..defaults
+        }
+    }
+
+    /// Problem transformations applied to every benchmark function.
+    fn run_options(&self) -> RunOptions {
+        RunOptions {
+            noise_sigma: self.noise_sigma,
+            shift: self.shift,
+            rotate: self.rotate,
+            maximize: false,
+            bounds_scale: self.bounds_scale,
+            optimum: None,
+            budget_iters: self.budget_iters,
+            budget_sec: self.budget_sec,
+            verbose: false,
+            cancel: None,
+        }
+    }
+}
+
+/// OQNLP parameter varied by `--sweep-param`.
+#[derive(Clone, Copy)]
+enum SweepParam {
+    PopulationSize,
+    Iterations,
+}
+
+impl SweepParam {
+    fn name(self) -> &'static str {
+        match self {
+            SweepParam::PopulationSize => "population_size",
+            SweepParam::Iterations => "iterations",
+        }
+    }
+}
+
+/// Parameter and values parsed from `--sweep-param`.
+#[derive(Clone)]
+struct Sweep {
+    param: SweepParam,
+    values: Vec<usize>,
+}
+
+impl Sweep {
+    /// `params` with the swept parameter set to `value`.
+    fn apply(&self, params: &OQNLPParams, value: usize) -> OQNLPParams {
+        match self.param {
+            SweepParam::PopulationSize => OQNLPParams { population_size: value, ..params.clone() },
+            SweepParam::Iterations => OQNLPParams { iterations: value, ..params.clone() },
+        }
+    }
+}
+
+fn parse_sweep(s: &str) -> Result<Sweep, String> {
+    let (name, values) =
+        s.split_once('=').ok_or_else(|| format!("expected <param>=<values>, got '{}'", s))?;
+    let param = match name.trim() {
+        "population_size" => SweepParam::PopulationSize,
+        "iterations" => SweepParam::Iterations,
+        other => {
+            return Err(format!(
+                "cannot sweep '{}', expected population_size or iterations",
+                other
+            ));
+        }
+    };
+    let values = values
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse::<usize>().map_err(|e| format!("invalid number '{}': {}", v, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if values.is_empty() {
+        return Err("no sweep values given".to_string());
+    }
+    if values.contains(&0) {
+        return Err(format!("{} must be positive", param.name()));
+    }
+    Ok(Sweep { param, values })
+}
+
+/// Sorted, de-duplicated dimensions parsed from `--dims`.
+#[derive(Clone)]
+struct DimList(Vec<usize>);
+
+fn parse_dims(s: &str) -> Result<DimList, String> {
+    let parse =
+        |v: &str| v.trim().parse::<usize>().map_err(|e| format!("invalid number '{}': {}", v, e));
+
+    let mut dims = Vec::new();
+    for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if let Some((start, rest)) = token.split_once('-') {
+            let (end, step) = match rest.split_once(':') {
+                Some((end, step)) => (parse(end)?, parse(step)?),
+                None => (parse(rest)?, 1),
+            };
+            let start = parse(start)?;
+            if step == 0 {
+                return Err(format!("step must be positive in '{}'", token));
+            }
+            if start > end {
+                return Err(format!("range start exceeds end in '{}'", token));
+            }
+            dims.extend((start..=end).step_by(step));
+        } else {
+            dims.push(parse(token)?);
+        }
+    }
+    dim_list(dims)
+}
+
+/// Checks and normalizes dimensions given by `--dims` or a config file.
+fn dim_list(mut dims: Vec<usize>) -> Result<DimList, String> {
+    if dims.contains(&0) {
+        return Err("dimensions must be positive".to_string());
+    }
+    if dims.is_empty() {
+        return Err("no dimensions given".to_string());
+    }
+    dims.sort_unstable();
+    dims.dedup();
+    Ok(DimList(dims))
+}
+
+fn parse_tolerance(s: &str) -> Result<f64, String> {
+    let tolerance =
+        s.trim().parse::<f64>().map_err(|e| format!("invalid number '{}': {}", s, e))?;
+    check_tolerance(tolerance)
+}
+
+/// Rejects tolerances given by `--tolerance` or a config file that no gap can be compared to.
+fn check_tolerance(tolerance: f64) -> Result<f64, String> {
+    if tolerance.is_finite() && tolerance >= 0.0 {
+        Ok(tolerance)
+    } else {
+        Err(format!("tolerance must be finite and not negative, got {}", tolerance))
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 struct StatPoint {
     dim: usize,
     success_rate: f64,
+    // 95% Wilson score interval of the success rate
+    #[serde(default)]
+    success_rate_ci_low: f64,
+    #[serde(default)]
+    success_rate_ci_high: f64,
     avg_runtime_sec: f64,
     std_runtime_sec: f64,
+    #[serde(default)]
+    ci95_runtime_sec: f64,
+    // Mean runtime without the fastest and slowest --trim-fraction of the runs
+    #[serde(default)]
+    trimmed_mean_runtime_sec: f64,
+    // Runtimes beyond 1.5 interquartile ranges from the quartiles
+    #[serde(default)]
+    runtime_outliers: usize,
+    // CPU time of the optimizing thread, only measured on Unix
+    #[serde(default)]
+    avg_cpu_sec: Option<f64>,
+    // Peak resident memory per run; not recorded with --parallel-functions
+    #[serde(default)]
+    avg_peak_memory_bytes: Option<f64>,
     avg_stage1_sec: f64,
     avg_stage2_sec: f64,
+    // Share of the average runtime spent in each stage
+    #[serde(default)]
+    stage1_fraction: f64,
+    #[serde(default)]
+    stage2_fraction: f64,
     avg_solution_set_size: f64,
     std_solution_set_size: f64,
     avg_best_obj: f64,
+    #[serde(default)]
+    avg_evaluations: f64,
+    // Runs that errored out rather than just missing the optimum
+    #[serde(default)]
+    failed_runs: usize,
+    // Runs abandoned after exceeding --timeout-sec
+    #[serde(default)]
+    timeouts: usize,
+    // Completed, failed and timed-out runs; varies per dimension with --ci-target
+    #[serde(default)]
+    runs_used: usize,
+    // Only available for functions with a known global argmin
+    #[serde(default)]
+    avg_solution_distance: Option<f64>,
+    // Solutions per run within tolerance of the global minimum
+    #[serde(default)]
+    avg_near_optimal: Option<f64>,
+    // Averaged over runs whose solution set holds at least two points
+    #[serde(default)]
+    avg_min_pairwise_distance: Option<f64>,
+    // Mean optimality gap per stage-2 iteration, only recorded with --plot-convergence
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    avg_convergence: Vec<f64>,
+    // Per-run runtimes, used for significance testing against a baseline
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    raw_runtimes: Vec<f64>,
 }
 
 #[derive(Serialize, Deserialize)]
 struct AllStats {
+    // Files saved before the format was versioned are version 1
+    #[serde(default = "legacy_schema_version")]
+    schema_version: u32,
     // Map function name to list of StatPoints
-    data: std::collections::HashMap<String, Vec<StatPoint>>,
+    data: BTreeMap<String, Vec<StatPoint>>,
+    // Missing in results saved by older versions
+    #[serde(default)]
+    metadata: Option<RunMetadata>,
 }
 
-fn main() {
-    let cli = Cli::parse();
-
-    let all_functions: Vec<Box<dyn BenchmarkFn>> = vec![
-        Box::new(Rosenbrock),
-        Box::new(Rastrigin),
-        Box::new(Ackley),
-        Box::new(Griewank),
-        Box::new(Levy),
-        Box::new(SixHumpCamel),
-        Box::new(CrossInTray),
-    ];
-
-    let functions_to_run: Vec<&Box<dyn BenchmarkFn>> = if let Some(name) = &cli.function {
-        all_functions.iter().filter(|f| f.name().to_lowercase() == name.to_lowercase()).collect()
-    } else {
-        all_functions.iter().collect()
-    };
+fn legacy_schema_version() -> u32 {
+    1
+}
 
-    let default_dims = if let Some(d) = cli.dim {
-        vec![d]
-    } else {
-        // Default dimensions
-        vec![10, 50, 100]
-    };
+fn unscaled_bounds() -> f64 {
+    1.0
+}
 
-    let mut current_run_stats = AllStats { data: std::collections::HashMap::new() };
-
-    for func in functions_to_run {
-        println!("Running benchmark for: {}", func.name());
-        let mut stats: Vec<StatPoint> = Vec::new();
-
-        let func_dims = func.supported_dims(&default_dims);
-
-        for &dim in &func_dims {
-            println!("  Dimension: {}", dim);
-            let mut runtimes = Vec::new();
-            let mut stage1_runtimes = Vec::new();
-            let mut stage2_runtimes = Vec::new();
-            let mut solution_set_sizes = Vec::new();
-            let mut successes = 0;
-            let mut best_objs = Vec::new();
-
-            for i in 0..cli.runs {
-                let seed = i as u64 * 702983;
-                let res = func.run(dim, seed);
-
-                runtimes.push(res.runtime.as_secs_f64());
-                stage1_runtimes.push(res.stage1_runtime.as_secs_f64());
-                stage2_runtimes.push(res.stage2_runtime.as_secs_f64());
-                solution_set_sizes.push(res.solution_set_size as f64);
-                best_objs.push(res.best_obj);
-                if res.success {
-                    successes += 1;
-                }
-            }
+/// Everything needed to reproduce a saved set of results.
+#[derive(Serialize, Deserialize, Clone)]
+struct RunMetadata {
+    seed_formula: String,
+    seeds: Vec<u64>,
+    runs: usize,
+    #[serde(default)]
+    warmup: usize,
+    params: ParamsMetadata,
+    #[serde(default)]
+    noise_sigma: f64,
+    #[serde(default)]
+    shift: f64,
+    #[serde(default)]
+    rotate: bool,
+    #[serde(default = "unscaled_bounds")]
+    bounds_scale: f64,
+    #[serde(default)]
+    budget_iters: Option<usize>,
+    #[serde(default)]
+    budget_sec: Option<f64>,
+    crate_version: String,
+    // Seconds since the Unix epoch
+    timestamp: u64,
+}
 
-            let success_rate = successes as f64 / cli.runs as f64;
-            let avg_runtime = mean(&runtimes);
-            let std_runtime = std_dev(&runtimes, avg_runtime);
-            let avg_sol_size = mean(&solution_set_sizes);
-            let std_sol_size = std_dev(&solution_set_sizes, avg_sol_size);
-            let avg_obj = mean(&best_objs);
+/// Serializable copy of the effective `OQNLPParams`.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct ParamsMetadata {
+    iterations: usize,
+    population_size: usize,
+    wait_cycle: usize,
+    threshold_factor: f64,
+    distance_factor: f64,
+    local_solver: String,
+}
 
-            println!(
-                "    SR: {:.2}, Avg T: {:.4}s, Avg SolSize: {:.1}",
-                success_rate, avg_runtime, avg_sol_size
-            );
+impl RunMetadata {
+    fn new(cli: &Cli, params: &OQNLPParams, seeds: &[u64]) -> Self {
+        RunMetadata {
+            seed_formula: match &cli.seeds {
+                Some(path) => format!("read from {}", path),
+                None => format!("i * {}", SEED_STEP),
+            } + if cli.seed_mixes_dim { ", mixed with the dimension" } else { "" },
+            seeds: seeds.to_vec(),
+            runs: seeds.len(),
+            warmup: cli.warmup,
+            params: ParamsMetadata {
+                iterations: params.iterations,
+                population_size: params.population_size,
+                wait_cycle: params.wait_cycle,
+                threshold_factor: params.threshold_factor,
+                distance_factor: params.distance_factor,
+                local_solver: format!("{:?}", params.local_solver_type),
+            },
+            noise_sigma: cli.noise_sigma,
+            shift: cli.shift,
+            bounds_scale: cli.bounds_scale,
+            rotate: cli.rotate,
+            budget_iters: cli.budget_iters,
+            budget_sec: cli.budget_sec,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
 
-            stats.push(StatPoint {
-                dim,
-                success_rate,
-                avg_runtime_sec: avg_runtime,
-                std_runtime_sec: std_runtime,
-                avg_stage1_sec: mean(&stage1_runtimes),
-                avg_stage2_sec: mean(&stage2_runtimes),
-                avg_solution_set_size: avg_sol_size,
-                std_solution_set_size: std_sol_size,
-                avg_best_obj: avg_obj,
-            });
+/// Adds the functions of `current` to `stored`. The stored metadata is replaced
+/// by the current one, since it describes the most recent run. Files in another
+/// schema version are refused, as one file can't hold points in two formats.
+fn merge_stats(
+    stored: &mut AllStats,
+    current: &AllStats,
+    on_conflict: OnConflict,
+) -> Result<(), String> {
+    if stored.schema_version != current.schema_version {
+        return Err(format!(
+            "stored results use schema version {}, not {}; upgrade them with --migrate-baseline first",
+            stored.schema_version, current.schema_version
+        ));
+    }
+    if let OnConflict::Error = on_conflict {
+        let mut conflicts: Vec<&str> = current
+            .data
+            .keys()
+            .filter(|name| stored.data.contains_key(*name))
+            .map(String::as_str)
+            .collect();
+        if !conflicts.is_empty() {
+            conflicts.sort_unstable();
+            return Err(format!("results already stored for {}", conflicts.join(", ")));
         }
+    }
+    if let (Some(base), Some(cur)) = (&stored.metadata, &current.metadata)
+        && base.params != cur.params
+    {
+        eprintln!("Warning: appending results produced with different OQNLP parameters");
+    }
+    for (name, points) in &current.data {
+        stored.data.insert(name.clone(), points.clone());
+    }
+    stored.metadata = current.metadata.clone();
+    Ok(())
+}
 
-        current_run_stats.data.insert(func.name().to_string(), stats.clone());
+/// Loads saved results, warning about the metrics an older file lacks since
+/// they are zero-filled and would skew comparisons.
+fn load_stats(path: &str) -> Result<AllStats, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let value: Value = serde_json::from_reader(BufReader::new(file)).map_err(|e| e.to_string())?;
+    let stats: AllStats = serde_json::from_value(value.clone()).map_err(|e| e.to_string())?;
+    if stats.schema_version != SCHEMA_VERSION {
+        eprintln!(
+            "Warning: {} uses results schema version {}, the current version is {}",
+            path, stats.schema_version, SCHEMA_VERSION
+        );
+        let missing = missing_metrics(&value);
+        if !missing.is_empty() {
+            eprintln!("  Missing metrics (zero-filled): {}", missing.join(", "));
+            eprintln!("  Run with --migrate-baseline to fill in the derivable ones");
+        }
     }
+    Ok(stats)
+}
 
-    // Save results if requested
-    if let Some(path) = &cli.save_json {
-        let file = File::create(path).expect("Failed to create output JSON file");
-        serde_json::to_writer_pretty(file, &current_run_stats).expect("Failed to write JSON");
-        println!("Saved stats to {}", path);
+/// Metrics added after version 1 that at least one stat point in `value` lacks.
+fn missing_metrics(value: &Value) -> Vec<&'static str> {
+    let points: Vec<&Map<String, Value>> = value["data"]
+        .as_object()
+        .into_iter()
+        .flat_map(|data| data.values())
+        .filter_map(Value::as_array)
+        .flatten()
+        .filter_map(Value::as_object)
+        .collect();
+    ADDED_METRICS.into_iter().filter(|m| points.iter().any(|p| !p.contains_key(*m))).collect()
+}
+
+/// Upgrades a results file to the current schema, deriving the missing metrics
+/// that follow from the stored ones and reporting those that can't be recovered.
+fn migrate_baseline(input: &str, output: &str, trim_fraction: f64) {
+    let file = File::open(input).unwrap_or_else(|e| panic!("Failed to open {}: {}", input, e));
+    let mut value: Value = serde_json::from_reader(BufReader::new(file))
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+    let missing_before = missing_metrics(&value);
+    let metadata_runs = value["metadata"]["runs"].as_u64();
+
+    if let Some(data) = value.get_mut("data").and_then(Value::as_object_mut) {
+        for point in data.values_mut().filter_map(Value::as_array_mut).flatten() {
+            let Some(point) = point.as_object_mut() else {
+                continue;
+            };
+            let get = |key: &str| point.get(key).and_then(Value::as_f64).unwrap_or_default();
+            let avg_runtime = get("avg_runtime_sec");
+            let stage1_fraction = time_fraction(get("avg_stage1_sec"), avg_runtime);
+            let stage2_fraction = time_fraction(get("avg_stage2_sec"), avg_runtime);
+            let std_runtime = get("std_runtime_sec");
+            let success_rate = get("success_rate");
+            let raw_runtimes: Vec<f64> = point
+                .get("raw_runtimes")
+                .and_then(Value::as_array)
+                .map(|r| r.iter().filter_map(Value::as_f64).collect())
+                .unwrap_or_default();
+            // Older versions did not track failures, so every run counted towards the stats
+            let runs = Some(raw_runtimes.len() as u64).filter(|&n| n > 0).or(metadata_runs);
+
+            point.entry("stage1_fraction").or_insert(stage1_fraction.into());
+            point.entry("stage2_fraction").or_insert(stage2_fraction.into());
+            point.entry("failed_runs").or_insert(0.into());
+            point.entry("timeouts").or_insert(0.into());
+            if !raw_runtimes.is_empty() {
+                let trimmed = trimmed_mean(&raw_runtimes, trim_fraction);
+                point.entry("trimmed_mean_runtime_sec").or_insert(trimmed.into());
+                point.entry("runtime_outliers").or_insert(iqr_outliers(&raw_runtimes).into());
+            }
+            if let Some(runs) = runs {
+                point.entry("runs_used").or_insert(runs.into());
+                let ci95 = 1.96 * std_runtime / (runs as f64).sqrt();
+                point.entry("ci95_runtime_sec").or_insert(ci95.into());
+                let successes = (success_rate * runs as f64).round() as usize;
+                let (low, high) = wilson_interval(successes, runs as usize);
+                point.entry("success_rate_ci_low").or_insert(low.into());
+                point.entry("success_rate_ci_high").or_insert(high.into());
+            }
+        }
     }
 
-    // Load baseline if requested and generate plots
-    let baseline_stats = if let Some(path) = &cli.load_baseline {
-        let file = File::open(path).expect("Failed to open baseline JSON file");
-        let reader = BufReader::new(file);
-        let loaded: AllStats =
-            serde_json::from_reader(reader).expect("Failed to parse baseline JSON");
-        println!("Loaded baseline stats from {}", path);
-        Some(loaded)
-    } else {
-        None
-    };
+    let missing_after = missing_metrics(&value);
+    let mut stats: AllStats = serde_json::from_value(value)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+    stats.schema_version = SCHEMA_VERSION;
+    let file = File::create(output).expect("Failed to create output JSON file");
+    serde_json::to_writer_pretty(file, &stats).expect("Failed to write JSON");
 
-    // Generate plots (comparing if baseline exists)
-    for (func_name, current_stats) in &current_run_stats.data {
-        let baseline = baseline_stats.as_ref().and_then(|b| b.data.get(func_name));
-        generate_plots(func_name, current_stats, baseline);
+    let derived: Vec<&str> =
+        missing_before.iter().filter(|m| !missing_after.contains(m)).copied().collect();
+    println!("Migrated {} to schema version {} in {}", input, SCHEMA_VERSION, output);
+    if !derived.is_empty() {
+        println!("  Derived: {}", derived.join(", "));
+    }
+    if !missing_after.is_empty() {
+        println!("  Not recoverable (zero-filled): {}", missing_after.join(", "));
     }
 }
 
-fn mean(data: &[f64]) -> f64 {
-    let sum: f64 = data.iter().sum();
-    sum / data.len() as f64
+/// Baseline name used in plots and reports: the file stem, or the full path
+/// when another baseline already uses that stem.
+fn baseline_label(path: &str, loaded: &[(String, AllStats)]) -> String {
+    let stem = Path::new(path).file_stem().map(|s| s.to_string_lossy().into_owned());
+    match stem {
+        Some(stem) if !loaded.iter().any(|(label, _)| *label == stem) => stem,
+        _ => path.to_string(),
+    }
 }
 
-fn std_dev(data: &[f64], mean: f64) -> f64 {
-    let variance = data
-        .iter()
-        .map(|value| {
-            let diff = mean - *value;
-            diff * diff
-        })
-        .sum::<f64>()
-        / data.len() as f64;
-    variance.sqrt()
+/// Combines a base seed with `salt` (a dimension or retry attempt) through the
+/// SplitMix64 finalizer, so nearby inputs give unrelated seeds.
+fn mix_seed(seed: u64, salt: u64) -> u64 {
+    let mut z = seed ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
-fn generate_plots(func_name: &str, current: &[StatPoint], baseline: Option<&Vec<StatPoint>>) {
-    let _ = std::fs::create_dir_all("plots");
+fn run_seeds(runs: usize) -> Vec<u64> {
+    (0..runs as u64).map(|i| i * SEED_STEP).collect()
+}
 
-    let x_vals: Vec<usize> = current.iter().map(|s| s.dim).collect();
+/// Parses seeds separated by commas and/or newlines, ignoring blank entries.
+fn parse_seeds(s: &str) -> Result<Vec<u64>, String> {
+    let seeds = s
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(|t| t.parse::<u64>().map_err(|e| format!("invalid seed '{}': {}", t, e)))
+        .collect::<Result<Vec<u64>, String>>()?;
+    if seeds.is_empty() {
+        return Err("no seeds given".to_string());
+    }
+    Ok(seeds)
+}
 
-    let mut plot = Plot::new();
+fn main() {
+    let mut cli = Cli::parse_with_config();
 
-    let layout = Layout::new()
-        .title(Title::with_text(format!("{} Benchmarks", func_name)))
-        .grid(LayoutGrid::new().rows(3).columns(1).pattern(GridPattern::Independent))
-        // Success Rate
-        .x_axis(Axis::new().title(Title::with_text("Dimension")))
+    if !(0.0..0.5).contains(&cli.trim_fraction) {
+        Cli::command().error(ErrorKind::InvalidValue, "--trim-fraction must be in [0, 0.5)").exit();
+    }
+
+    if let Some([input, output]) = cli.migrate_baseline.as_deref() {
+        migrate_baseline(input, output, cli.trim_fraction);
+        return;
+    }
+
+    let params = cli.oqnlp_params();
+    let param_sets: Vec<OQNLPParams> = match &cli.sweep_param {
+        Some(sweep) => sweep.values.iter().map(|&v| sweep.apply(&params, v)).collect(),
+        None => vec![params.clone()],
+    };
+    for p in &param_sets {
+        if p.iterations > p.population_size {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!(
+                        "iterations ({}) must not exceed population size ({})",
+                        p.iterations, p.population_size
+                    ),
+                )
+                .exit();
+        }
+    }
+    if !cli.shift.is_finite() {
+        Cli::command().error(ErrorKind::InvalidValue, "--shift must be finite").exit();
+    }
+    if !(cli.bounds_scale.is_finite() && cli.bounds_scale > 0.0) {
+        Cli::command()
+            .error(ErrorKind::InvalidValue, "--bounds-scale must be positive and finite")
+            .exit();
+    }
+    if cli.budget_iters == Some(0) {
+        Cli::command().error(ErrorKind::InvalidValue, "--budget-iters must be positive").exit();
+    }
+    if cli.budget_sec.is_some_and(|b| !(b.is_finite() && b > 0.0)) {
+        Cli::command()
+            .error(ErrorKind::InvalidValue, "--budget-sec must be positive and finite")
+            .exit();
+    }
+    if cli.noise_sigma < 0.0 {
+        Cli::command().error(ErrorKind::InvalidValue, "--noise-sigma must not be negative").exit();
+    }
+    if cli.ci_target.is_some() && cli.max_runs < cli.runs {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("--max-runs ({}) must be at least --runs ({})", cli.max_runs, cli.runs),
+            )
+            .exit();
+    }
+
+    let seeds = match &cli.seeds {
+        Some(path) => {
+            let contents = std::fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("Failed to read seeds file {}: {}", path, e));
+            parse_seeds(&contents).unwrap_or_else(|e| {
+                Cli::command().error(ErrorKind::InvalidValue, format!("{}: {}", path, e)).exit()
+            })
+        }
+        None if cli.warmup_advances_seeds => {
+            run_seeds(cli.warmup + cli.seed_count()).split_off(cli.warmup)
+        }
+        None => run_seeds(cli.seed_count()),
+    };
+    let warmup_seeds: Vec<u64> = if cli.warmup_advances_seeds {
+        run_seeds(cli.warmup)
+    } else {
+        seeds.iter().cycle().take(cli.warmup).copied().collect()
+    };
+
+    let all_functions = all_functions(cli.variant);
+
+    let default_dims = if let Some(d) = cli.dim {
+        vec![d]
+    } else if let Some(DimList(dims)) = &cli.dims {
+        dims.clone()
+    } else {
+        // Default dimensions
+        vec![10, 50, 100]
+    };
+
+    if cli.list_functions {
+        print_functions(&all_functions, &default_dims);
+        return;
+    }
+
+    let selected = cli.functions.clone().or_else(|| cli.function.clone().map(|name| vec![name]));
+    let mut functions_to_run: Vec<Arc<dyn BenchmarkFn>> = match selected {
+        Some(names) => select_functions(all_functions, &names)
+            .unwrap_or_else(|e| Cli::command().error(ErrorKind::InvalidValue, e).exit()),
+        None => all_functions,
+    };
+    if let Some(tag) = &cli.tag {
+        functions_to_run = with_tag(functions_to_run, tag);
+    }
+    if cli.use_gradients {
+        let variants: Vec<Arc<dyn BenchmarkFn>> = functions_to_run
+            .iter()
+            .filter(|f| f.has_gradient())
+            .map(|f| Arc::new(WithGradients::new(Arc::clone(f))) as Arc<dyn BenchmarkFn>)
+            .collect();
+        functions_to_run.extend(variants);
+    }
+
+    if let Some(reference) = &cli.normalize_to
+        && cli.from_raw.is_none()
+        && !functions_to_run.iter().any(|f| f.name().eq_ignore_ascii_case(reference))
+    {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("--normalize-to {} requires {} to be run as well", reference, reference),
+            )
+            .exit();
+    }
+
+    if cli.dry_run {
+        print_plan(
+            &functions_to_run,
+            &default_dims,
+            &seeds,
+            &warmup_seeds,
+            &params,
+            &cli.run_options(),
+            cli.seed_mixes_dim,
+        );
+        return;
+    }
+
+    if let Some(path) = &cli.only_failures {
+        let failures = failed_runs(&load_raw_dump(path));
+        println!("Rerunning {} unsuccessful runs from {}", failures.len(), path);
+        rerun_failures(&failures, &functions_to_run, &cli);
+        return;
+    }
+
+    if let Some(sweep) = cli.sweep_param.clone() {
+        let curves =
+            run_sweep(&sweep, &functions_to_run, &default_dims, &seeds, &warmup_seeds, &mut cli);
+        for (func_name, curves) in &curves {
+            generate_sweep_plot(
+                func_name,
+                sweep.param,
+                curves,
+                cli.timing,
+                cli.error_bars,
+                cli.plot_format,
+            );
+        }
+        return;
+    }
+
+    let raw_runs: BTreeMap<String, Vec<DimRuns>> = if let Some(path) = &cli.from_raw {
+        let raw_runs = load_raw_dump(path);
+        println!("Loaded raw runs from {}", path);
+        raw_runs
+    } else {
+        let checkpoint = cli.checkpoint.as_deref().map(|path| {
+            let settings = CheckpointSettings {
+                dims: default_dims.clone(),
+                metadata: RunMetadata::new(&cli, &params, &seeds),
+            };
+            Checkpoint::open(path, settings).unwrap_or_else(|e| {
+                eprintln!("Error: cannot resume from {}: {}", path, e);
+                std::process::exit(1);
+            })
+        });
+        if let Some(checkpoint) = &checkpoint {
+            let completed = checkpoint.completed_cells();
+            if completed > 0 {
+                println!("Resuming from checkpoint with {} completed cells", completed);
+            }
+        }
+        let runs_per_dim = (warmup_seeds.len() + seeds.len()) as u64;
+        let progress = (!cli.quiet && std::io::stdout().is_terminal()).then(|| {
+            let total_runs: u64 = functions_to_run
+                .iter()
+                .map(|func| func.supported_dims(&default_dims).len() as u64 * runs_per_dim)
+                .sum();
+            Progress::new(total_runs)
+        });
+
+        let results: Vec<(String, Vec<DimRuns>)> = if cli.parallel_functions {
+            functions_to_run
+                .par_iter()
+                .map(|func| {
+                    let function_runs =
+                        func.supported_dims(&default_dims).len() as u64 * runs_per_dim;
+                    let mut log = ProgressLog::buffered(progress.as_ref().map(|p| {
+                        let bar = p.function_bar(func.name(), function_runs);
+                        (p.clone(), bar)
+                    }));
+                    let runs = benchmark_function(
+                        func,
+                        &default_dims,
+                        &seeds,
+                        &warmup_seeds,
+                        &cli,
+                        checkpoint.as_ref(),
+                        &mut log,
+                    );
+                    log.flush();
+                    (func.name().to_string(), runs)
+                })
+                .collect()
+        } else {
+            functions_to_run
+                .iter()
+                .map(|func| {
+                    let mut log = ProgressLog::live(progress.clone());
+                    let runs = benchmark_function(
+                        func,
+                        &default_dims,
+                        &seeds,
+                        &warmup_seeds,
+                        &cli,
+                        checkpoint.as_ref(),
+                        &mut log,
+                    );
+                    (func.name().to_string(), runs)
+                })
+                .collect()
+        };
+        if let Some(progress) = &progress {
+            progress.overall.finish_and_clear();
+        }
+        results.into_iter().collect()
+    };
+    let current_run_stats = AllStats {
+        schema_version: SCHEMA_VERSION,
+        data: raw_runs
+            .iter()
+            .map(|(name, per_dim)| {
+                // Cells without a completed run have no statistics to save
+                let stats =
+                    per_dim.iter().filter_map(|d| aggregate(d, cli.trim_fraction)).collect();
+                (name.clone(), stats)
+            })
+            .collect(),
+        metadata: Some(RunMetadata::new(&cli, &params, &seeds)),
+    };
+
+    // Save results if requested
+    if let Some(path) = &cli.save_json {
+        let file = File::create(path).expect("Failed to create output JSON file");
+        serde_json::to_writer_pretty(file, &current_run_stats).expect("Failed to write JSON");
+        println!("Saved stats to {}", path);
+    }
+
+    if let Some(path) = &cli.append_json {
+        let mut stored = if Path::new(path).exists() {
+            load_stats(path).unwrap_or_else(|e| {
+                Cli::command().error(ErrorKind::InvalidValue, format!("{}: {}", path, e)).exit()
+            })
+        } else {
+            AllStats { schema_version: SCHEMA_VERSION, data: BTreeMap::new(), metadata: None }
+        };
+        if let Err(e) = merge_stats(&mut stored, &current_run_stats, cli.on_conflict) {
+            eprintln!("Error: cannot append to {}: {}", path, e);
+            std::process::exit(1);
+        }
+        let file = File::create(path).expect("Failed to create output JSON file");
+        serde_json::to_writer_pretty(file, &stored).expect("Failed to write JSON");
+        println!("Appended stats to {}", path);
+    }
+
+    if let Some(path) = &cli.dump_raw {
+        let file = File::create(path).expect("Failed to create raw dump file");
+        serde_json::to_writer_pretty(file, &raw_dump(&raw_runs)).expect("Failed to write raw dump");
+        println!("Saved raw runs to {}", path);
+    }
+
+    if let Some(path) = &cli.save_csv {
+        std::fs::write(path, stats_to_csv(&current_run_stats)).expect("Failed to write CSV");
+        println!("Saved CSV stats to {}", path);
+    }
+
+    if let Some(path) = &cli.export_table {
+        std::fs::write(path, results_table(&raw_runs, &functions_to_run))
+            .expect("Failed to write results table");
+        println!("Exported results table to {}", path);
+    }
+
+    // Load baselines if requested and generate plots
+    let mut baseline_stats: Vec<(String, AllStats)> = Vec::new();
+    for path in &cli.load_baseline {
+        let loaded = load_stats(path).unwrap_or_else(|e| {
+            Cli::command().error(ErrorKind::InvalidValue, format!("{}: {}", path, e)).exit()
+        });
+        println!("Loaded baseline stats from {}", path);
+        let label = baseline_label(path, &baseline_stats);
+        if let (Some(base), Some(cur)) = (&loaded.metadata, &current_run_stats.metadata) {
+            if base.runs != cur.runs {
+                eprintln!(
+                    "Warning: baseline {} used {} runs per dimension, current run used {}",
+                    label, base.runs, cur.runs
+                );
+            }
+            if base.params != cur.params {
+                eprintln!(
+                    "Warning: baseline {} was produced with different OQNLP parameters",
+                    label
+                );
+            }
+            if base.noise_sigma != cur.noise_sigma {
+                eprintln!(
+                    "Warning: baseline {} used noise sigma {}, current run used {}",
+                    label, base.noise_sigma, cur.noise_sigma
+                );
+            }
+            if base.shift != cur.shift {
+                eprintln!(
+                    "Warning: baseline {} used shift {}, current run used {}",
+                    label, base.shift, cur.shift
+                );
+            }
+            if base.bounds_scale != cur.bounds_scale {
+                eprintln!(
+                    "Warning: baseline {} scaled the bounds by {}, current run by {}",
+                    label, base.bounds_scale, cur.bounds_scale
+                );
+            }
+            if (base.budget_iters, base.budget_sec) != (cur.budget_iters, cur.budget_sec) {
+                eprintln!("Warning: baseline {} used a different run budget", label);
+            }
+            if base.rotate != cur.rotate {
+                eprintln!(
+                    "Warning: baseline {} was run {} rotation, current run {}",
+                    label,
+                    if base.rotate { "with" } else { "without" },
+                    if cur.rotate { "with" } else { "without" }
+                );
+            }
+        }
+        print_runtime_significance(&current_run_stats, &label, &loaded);
+        baseline_stats.push((label, loaded));
+    }
+
+    if let Some(path) = &cli.markdown {
+        // Deltas are reported against the first baseline only
+        let baseline = baseline_stats.first().map(|(_, stats)| stats);
+        let markdown = stats_to_markdown(&current_run_stats, baseline);
+        std::fs::write(path, markdown).expect("Failed to write Markdown summary");
+        println!("Saved Markdown summary to {}", path);
+    }
+
+    print_runtime_scaling(&current_run_stats);
+    print_leaderboard(&current_run_stats, &cli.score_weights);
+
+    let normalized = cli.normalize_to.as_ref().map(|reference| {
+        let ratios = normalized_runtimes(&current_run_stats, reference).unwrap_or_else(|e| {
+            eprintln!("Error: cannot normalize runtimes: {}", e);
+            std::process::exit(1);
+        });
+        print_normalized_runtimes(&ratios, reference);
+        (reference, ratios)
+    });
+
+    if cli.no_plots {
+        return;
+    }
+
+    // Generate plots (comparing if baseline exists)
+    let plots_start = SystemTime::now();
+    for (func_name, current_stats) in &current_run_stats.data {
+        let baselines: Vec<(&str, &[StatPoint])> = baseline_stats
+            .iter()
+            .filter_map(|(label, stats)| {
+                stats.data.get(func_name).map(|points| (label.as_str(), points.as_slice()))
+            })
+            .collect();
+        generate_plots(
+            func_name,
+            current_stats,
+            &baselines,
+            cli.timing,
+            cli.error_bars,
+            cli.reference_curve,
+            cli.plot_format,
+        );
+        if cli.plot_convergence {
+            generate_convergence_plot(func_name, current_stats, cli.plot_format);
+        }
+        if cli.boxplots {
+            generate_boxplots(func_name, &raw_runs[func_name], cli.plot_format);
+        }
+        if cli.obj_histogram {
+            generate_objective_histograms(func_name, &raw_runs[func_name], cli.plot_format);
+        }
+        if cli.obj_violin {
+            generate_objective_violins(func_name, &raw_runs[func_name], cli.plot_format);
+        }
+        if cli.performance_profile {
+            for dim_runs in &raw_runs[func_name] {
+                // Failed and timed-out runs never reach the optimum
+                let unfinished = dim_runs.failed + dim_runs.timeouts;
+                let runtimes: Vec<f64> = dim_runs
+                    .runs
+                    .iter()
+                    .map(|r| r.runtime_sec)
+                    .chain(std::iter::repeat_n(f64::INFINITY, unfinished))
+                    .collect();
+                let success: Vec<bool> = dim_runs
+                    .runs
+                    .iter()
+                    .map(|r| r.success)
+                    .chain(std::iter::repeat_n(false, unfinished))
+                    .collect();
+                generate_performance_profile(
+                    func_name,
+                    dim_runs.dim,
+                    &runtimes,
+                    &success,
+                    cli.plot_format,
+                );
+            }
+        }
+    }
+    if cli.overview {
+        generate_overview(&current_run_stats, cli.plot_format);
+    }
+    if cli.heatmap {
+        generate_success_heatmap(&current_run_stats, cli.plot_format);
+    }
+    if let Some((reference, ratios)) = &normalized {
+        generate_normalized_runtime_plot(ratios, reference, cli.plot_format);
+    }
+    write_plot_index(&current_run_stats, plots_start);
+}
+
+/// Writes `plots/index.html`, linking every plot written since `since` grouped by
+/// function, so plots left over from earlier runs are not listed.
+fn write_plot_index(stats: &AllStats, since: SystemTime) {
+    let Ok(entries) = std::fs::read_dir("plots") else {
+        return;
+    };
+    let mut files: Vec<String> = entries
+        .filter_map(Result::ok)
+        // File timestamps can be coarser than the clock, so allow a second of slack
+        .filter(|e| {
+            e.metadata()
+                .and_then(|m| m.modified())
+                .is_ok_and(|t| t + Duration::from_secs(1) >= since)
+        })
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter(|name| name != "index.html")
+        .collect();
+    files.sort();
+
+    // Plot files are named after the lowercased function; the longest match wins so
+    // that e.g. rosenbrock_lbfgs_* is not filed under Rosenbrock
+    let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for file in &files {
+        let owner = stats
+            .data
+            .keys()
+            .filter(|name| file.starts_with(&format!("{}_", name.to_lowercase())))
+            .max_by_key(|name| name.len())
+            .map_or("Other", String::as_str);
+        groups.entry(owner).or_default().push(file);
+    }
+
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Benchmark plots</title>\n</head>\n<body>\n<h1>Benchmark plots</h1>\n",
+    );
+    if let Some(meta) = &stats.metadata {
+        let dims: BTreeSet<usize> = stats.data.values().flatten().map(|p| p.dim).collect();
+        let dims: Vec<String> = dims.iter().map(|d| d.to_string()).collect();
+        html.push_str(&format!(
+            "<ul>\n<li>Date: {}</li>\n<li>Runs: {} (+{} warmup)</li>\n<li>Dimensions: {}</li>\n\
+             <li>Version: {}</li>\n</ul>\n",
+            format_utc(meta.timestamp),
+            meta.runs,
+            meta.warmup,
+            dims.join(", "),
+            meta.crate_version
+        ));
+    }
+    if groups.is_empty() {
+        html.push_str("<p>No plots were generated.</p>\n");
+    }
+    for (owner, files) in &groups {
+        html.push_str(&format!("<h2>{}</h2>\n<ul>\n", owner));
+        for file in files {
+            html.push_str(&format!("<li><a href=\"{0}\">{0}</a></li>\n", file));
+        }
+        html.push_str("</ul>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+
+    match std::fs::write("plots/index.html", html) {
+        Ok(()) => println!("Saved plot index to plots/index.html"),
+        Err(e) => eprintln!("Warning: failed to write plots/index.html: {}", e),
+    }
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time.
+fn format_utc(timestamp: u64) -> String {
+    let (days, secs) = (timestamp / 86_400, timestamp % 86_400);
+    // Civil-from-days conversion for the proleptic Gregorian calendar
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Progress bars over every run of the sweep, drawn to stdout. Under
+/// --parallel-functions each function also gets its own bar below the overall one.
+#[derive(Clone)]
+struct Progress {
+    multi: MultiProgress,
+    overall: ProgressBar,
+}
+
+impl Progress {
+    fn new(total_runs: u64) -> Self {
+        let multi = MultiProgress::with_draw_target(ProgressDrawTarget::stdout());
+        // indicatif estimates the ETA from a moving average of the recent per-run times
+        let overall = multi.add(ProgressBar::new(total_runs)).with_style(
+            ProgressStyle::with_template(
+                "[{elapsed_precise}] {wide_bar} {pos}/{len} runs, ETA {eta} {msg}",
+            )
+            .unwrap(),
+        );
+        Self { multi, overall }
+    }
+
+    fn function_bar(&self, name: &str, runs: u64) -> ProgressBar {
+        self.multi.add(ProgressBar::new(runs)).with_prefix(name.to_string()).with_style(
+            ProgressStyle::with_template("  {prefix:>24} {bar:30} {pos}/{len} {msg}").unwrap(),
+        )
+    }
+}
+
+/// Collects progress output, either printing it immediately or holding it
+/// until the benchmark of a function finishes so parallel runs don't interleave.
+/// Also advances the progress bars, if any, after every run.
+struct ProgressLog {
+    buffer: Option<String>,
+    progress: Option<Progress>,
+    // Bar of this function alone, only used when functions run in parallel
+    function_bar: Option<ProgressBar>,
+}
+
+impl ProgressLog {
+    fn live(progress: Option<Progress>) -> Self {
+        Self { buffer: None, progress, function_bar: None }
+    }
+
+    fn buffered(progress: Option<(Progress, ProgressBar)>) -> Self {
+        let (progress, function_bar) = progress.unzip();
+        Self { buffer: Some(String::new()), progress, function_bar }
+    }
+
+    fn line(&mut self, msg: String) {
+        match (&mut self.buffer, &self.progress) {
+            (Some(buffer), _) => {
+                buffer.push_str(&msg);
+                buffer.push('\n');
+            }
+            // Printing through the bars keeps them below the log lines
+            (None, Some(progress)) => {
+                let _ = progress.multi.println(msg);
+            }
+            (None, None) => println!("{}", msg),
+        }
+    }
+
+    /// Records one finished (or abandoned) run of `func_name` at `dim`.
+    fn tick(&self, func_name: &str, dim: usize) {
+        if let Some(progress) = &self.progress {
+            progress.overall.inc(1);
+            match &self.function_bar {
+                Some(bar) => {
+                    bar.inc(1);
+                    bar.set_message(format!("dim {}", dim));
+                }
+                None => progress.overall.set_message(format!("{} (dim {})", func_name, dim)),
+            }
+        }
+    }
+
+    /// Removes `runs` planned runs that will not be executed, e.g. after --ci-target is met.
+    fn skip(&self, runs: u64) {
+        if let Some(progress) = &self.progress {
+            progress.overall.dec_length(runs);
+        }
+        if let Some(bar) = &self.function_bar {
+            bar.dec_length(runs);
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(bar) = self.function_bar.take() {
+            bar.finish_and_clear();
+        }
+        if let Some(buffer) = self.buffer.take() {
+            match &self.progress {
+                Some(progress) => {
+                    let _ = progress.multi.println(buffer.trim_end());
+                }
+                None => print!("{}", buffer),
+            }
+        }
+    }
+}
+
+/// Outcome of a single OQNLP run, kept until all reports are generated.
+struct RunRecord {
+    seed: u64,
+    runtime_sec: f64,
+    cpu_sec: Option<f64>,
+    peak_memory_bytes: Option<u64>,
+    stage1_sec: f64,
+    stage2_sec: f64,
+    solution_set_size: usize,
+    // Solutions within tolerance of the global minimum
+    near_optimal: usize,
+    min_pairwise_distance: Option<f64>,
+    best_obj: f64,
+    evaluations: u64,
+    success: bool,
+    distance: Option<f64>,
+    // Optimality gap per stage-2 iteration, empty unless --plot-convergence
+    convergence: Vec<f64>,
+    // Stage 2 stopped at --budget-iters or --budget-sec
+    budget_exhausted: bool,
+    // Best point found, empty unless --dump-points
+    best_point: Vec<f64>,
+}
+
+/// Serializable form of a `RunRecord`, written by --dump-raw and read back by --from-raw.
+#[derive(Serialize, Deserialize, Clone)]
+struct RawRun {
+    seed: u64,
+    objective: f64,
+    runtime_sec: f64,
+    #[serde(default)]
+    cpu_sec: Option<f64>,
+    #[serde(default)]
+    peak_memory_bytes: Option<u64>,
+    stage1_sec: f64,
+    stage2_sec: f64,
+    solution_set_size: usize,
+    #[serde(default)]
+    near_optimal: usize,
+    #[serde(default)]
+    min_pairwise_distance: Option<f64>,
+    #[serde(default)]
+    evaluations: u64,
+    success: bool,
+    #[serde(default)]
+    distance: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    convergence: Vec<f64>,
+    #[serde(default)]
+    budget_exhausted: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    best_point: Vec<f64>,
+}
+
+impl From<&RunRecord> for RawRun {
+    fn from(r: &RunRecord) -> Self {
+        RawRun {
+            seed: r.seed,
+            objective: r.best_obj,
+            runtime_sec: r.runtime_sec,
+            cpu_sec: r.cpu_sec,
+            peak_memory_bytes: r.peak_memory_bytes,
+            stage1_sec: r.stage1_sec,
+            stage2_sec: r.stage2_sec,
+            solution_set_size: r.solution_set_size,
+            near_optimal: r.near_optimal,
+            min_pairwise_distance: r.min_pairwise_distance,
+            evaluations: r.evaluations,
+            success: r.success,
+            distance: r.distance,
+            convergence: r.convergence.clone(),
+            budget_exhausted: r.budget_exhausted,
+            best_point: r.best_point.clone(),
+        }
+    }
+}
+
+impl From<RawRun> for RunRecord {
+    fn from(r: RawRun) -> Self {
+        RunRecord {
+            seed: r.seed,
+            runtime_sec: r.runtime_sec,
+            cpu_sec: r.cpu_sec,
+            peak_memory_bytes: r.peak_memory_bytes,
+            stage1_sec: r.stage1_sec,
+            stage2_sec: r.stage2_sec,
+            solution_set_size: r.solution_set_size,
+            near_optimal: r.near_optimal,
+            min_pairwise_distance: r.min_pairwise_distance,
+            best_obj: r.objective,
+            evaluations: r.evaluations,
+            success: r.success,
+            distance: r.distance,
+            convergence: r.convergence,
+            budget_exhausted: r.budget_exhausted,
+            best_point: r.best_point,
+        }
+    }
+}
+
+/// Completed runs of one dimension, with the number of failed and timed-out runs.
+#[derive(Serialize, Deserialize, Clone)]
+struct RawDim {
+    runs: Vec<RawRun>,
+    failed: usize,
+    timeouts: usize,
+}
+
+impl From<&DimRuns> for RawDim {
+    fn from(dim_runs: &DimRuns) -> Self {
+        RawDim {
+            runs: dim_runs.runs.iter().map(RawRun::from).collect(),
+            failed: dim_runs.failed,
+            timeouts: dim_runs.timeouts,
+        }
+    }
+}
+
+impl RawDim {
+    fn into_dim_runs(self, dim: usize) -> DimRuns {
+        DimRuns {
+            dim,
+            runs: self.runs.into_iter().map(RunRecord::from).collect(),
+            failed: self.failed,
+            timeouts: self.timeouts,
+        }
+    }
+}
+
+/// Dimension entry of a raw dump; older dumps hold just the list of completed runs.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredRawDim {
+    Current(RawDim),
+    Legacy(Vec<RawRun>),
+}
+
+/// Runs keyed by function name and dimension.
+type RawDump = BTreeMap<String, BTreeMap<usize, RawDim>>;
+
+fn raw_dump(raw_runs: &BTreeMap<String, Vec<DimRuns>>) -> RawDump {
+    raw_runs
+        .iter()
+        .map(|(name, per_dim)| {
+            let dims =
+                per_dim.iter().map(|dim_runs| (dim_runs.dim, RawDim::from(dim_runs))).collect();
+            (name.clone(), dims)
+        })
+        .collect()
+}
+
+/// Reads a --dump-raw file back into the per-run records it was written from.
+fn load_raw_dump(path: &str) -> BTreeMap<String, Vec<DimRuns>> {
+    let file = File::open(path).unwrap_or_else(|e| panic!("Failed to open {}: {}", path, e));
+    let dump: BTreeMap<String, BTreeMap<usize, StoredRawDim>> =
+        serde_json::from_reader(BufReader::new(file))
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path, e));
+    dump.into_iter()
+        .map(|(name, dims)| {
+            let per_dim = dims
+                .into_iter()
+                .map(|(dim, stored)| {
+                    let raw_dim = match stored {
+                        StoredRawDim::Current(raw_dim) => raw_dim,
+                        StoredRawDim::Legacy(runs) => RawDim { runs, failed: 0, timeouts: 0 },
+                    };
+                    raw_dim.into_dim_runs(dim)
+                })
+                .collect();
+            (name, per_dim)
+        })
+        .collect()
+}
+
+/// Contents of a --checkpoint file.
+#[derive(Serialize, Deserialize)]
+struct CheckpointState {
+    settings: CheckpointSettings,
+    // Completed (function, dimension) cells, in the order they finished
+    completed: Vec<(String, usize)>,
+    runs: RawDump,
+}
+
+/// Settings of the sweep that wrote a checkpoint. Its cells are only resumed by a
+/// sweep with the same settings, so that all cells of the results stay comparable.
+#[derive(Serialize, Deserialize)]
+struct CheckpointSettings {
+    dims: Vec<usize>,
+    metadata: RunMetadata,
+}
+
+impl CheckpointSettings {
+    /// Names of the settings that differ between `self` and `other`.
+    fn changes_from(&self, other: &CheckpointSettings) -> Vec<&'static str> {
+        let (a, b) = (&self.metadata, &other.metadata);
+        [
+            ("dimensions", self.dims != other.dims),
+            (
+                "seeds",
+                a.seeds != b.seeds || a.seed_formula != b.seed_formula || a.warmup != b.warmup,
+            ),
+            ("OQNLP parameters", a.params != b.params),
+            (
+                "problem transforms",
+                a.noise_sigma != b.noise_sigma
+                    || a.shift != b.shift
+                    || a.rotate != b.rotate
+                    || a.bounds_scale != b.bounds_scale,
+            ),
+            ("run budgets", a.budget_iters != b.budget_iters || a.budget_sec != b.budget_sec),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+        .collect()
+    }
+}
+
+/// Completed cells of an interrupted sweep, saved after every (function, dimension)
+/// so that a restarted sweep can skip them.
+struct Checkpoint {
+    path: String,
+    state: Mutex<CheckpointState>,
+}
+
+impl Checkpoint {
+    /// Resumes from `path`, or starts an empty checkpoint if it doesn't exist yet.
+    /// Fails if the checkpoint was written with other settings.
+    fn open(path: &str, settings: CheckpointSettings) -> Result<Self, String> {
+        let state = if Path::new(path).exists() {
+            let file =
+                File::open(path).unwrap_or_else(|e| panic!("Failed to open {}: {}", path, e));
+            let state: CheckpointState = serde_json::from_reader(BufReader::new(file))
+                .unwrap_or_else(|e| panic!("Failed to parse checkpoint {}: {}", path, e));
+            let changes = state.settings.changes_from(&settings);
+            if !changes.is_empty() {
+                return Err(format!("it was written with different {}", changes.join(", ")));
+            }
+            state
+        } else {
+            CheckpointState { settings, completed: Vec::new(), runs: RawDump::new() }
+        };
+        Ok(Checkpoint { path: path.to_string(), state: Mutex::new(state) })
+    }
+
+    fn completed_cells(&self) -> usize {
+        self.state.lock().unwrap().completed.len()
+    }
+
+    /// Runs of `func_name` at `dim` if that cell was completed before.
+    fn restore(&self, func_name: &str, dim: usize) -> Option<DimRuns> {
+        let state = self.state.lock().unwrap();
+        let raw_dim = state.runs.get(func_name)?.get(&dim)?;
+        Some(raw_dim.clone().into_dim_runs(dim))
+    }
+
+    /// Records a completed cell and rewrites the checkpoint. The file is replaced by
+    /// renaming a fully written temporary file, so a crash never leaves it truncated.
+    fn record(&self, func_name: &str, dim_runs: &DimRuns) {
+        let mut state = self.state.lock().unwrap();
+        state.runs.entry(func_name.to_string()).or_default().insert(dim_runs.dim, dim_runs.into());
+        state.completed.push((func_name.to_string(), dim_runs.dim));
+
+        let tmp = format!("{}.tmp", self.path);
+        let file = File::create(&tmp).expect("Failed to create checkpoint file");
+        serde_json::to_writer(&file, &*state).expect("Failed to write checkpoint");
+        file.sync_all().expect("Failed to write checkpoint");
+        std::fs::rename(&tmp, &self.path).expect("Failed to replace checkpoint");
+    }
+}
+
+/// All runs of one function at one dimension.
+struct DimRuns {
+    dim: usize,
+    // Completed runs only
+    runs: Vec<RunRecord>,
+    // Runs that returned an error, counted as non-successes
+    failed: usize,
+    // Runs abandoned after --timeout-sec, counted as non-successes
+    timeouts: usize,
+}
+
+fn benchmark_function(
+    func: &Arc<dyn BenchmarkFn>,
+    default_dims: &[usize],
+    seeds: &[u64],
+    warmup_seeds: &[u64],
+    cli: &Cli,
+    checkpoint: Option<&Checkpoint>,
+    log: &mut ProgressLog,
+) -> Vec<DimRuns> {
+    log.line(format!("Running benchmark for: {}", func.name()));
+    let mut results = Vec::new();
+
+    let func_dims = func.supported_dims(default_dims);
+    let tolerance = cli.tolerance.unwrap_or_else(|| func.success_tolerance());
+    let params = cli.oqnlp_params();
+    let opts = RunOptions { maximize: func.is_maximization(), ..cli.run_options() };
+    // Turns an objective into its gap to the optimum, which is positive either way
+    let sign = if func.is_maximization() { -1.0 } else { 1.0 };
+
+    for &dim in &func_dims {
+        if let Some(dim_runs) = checkpoint.and_then(|c| c.restore(func.name(), dim)) {
+            log.line(format!("  Dimension: {} (restored from checkpoint)", dim));
+            log.skip((warmup_seeds.len() + seeds.len()) as u64);
+            results.push(dim_runs);
+            continue;
+        }
+        log.line(format!("  Dimension: {}", dim));
+        let global_minimum = func.global_minimum(dim);
+        let opts = RunOptions {
+            optimum: opts.nearest_argmin(func.as_ref(), dim, &Array1::zeros(dim)),
+            ..opts.clone()
+        };
+        let mut runs: Vec<RunRecord> = Vec::new();
+        let mut failed = 0;
+        let mut timeouts = 0;
+
+        for &seed in warmup_seeds {
+            let _ = run_with_timeout(
+                func,
+                dim,
+                cli.run_seed(seed, dim),
+                &params,
+                &opts,
+                cli.timeout_sec,
+            );
+            log.tick(func.name(), dim);
+        }
+
+        for (i, &seed) in seeds.iter().enumerate() {
+            let base_seed = cli.run_seed(seed, dim);
+            if cli.ci_target.is_some() {
+                let trials = runs.len() + failed + timeouts;
+                let successes = runs.iter().filter(|r| r.success).count();
+                if cli.ci_target_met(successes, trials) {
+                    log.skip((seeds.len() - i) as u64);
+                    break;
+                }
+            }
+
+            let mut seed = base_seed;
+            let mut attempt = 0;
+            let outcome = loop {
+                let outcome = run_with_timeout(func, dim, seed, &params, &opts, cli.timeout_sec);
+                match &outcome {
+                    Some(Err(e)) if e.is_degenerate() && attempt < cli.retry => {
+                        attempt += 1;
+                        let retry_seed = mix_seed(base_seed, attempt);
+                        log.line(format!(
+                            "    Run {} (seed {}) degenerate: {}; retrying with seed {}",
+                            i, seed, e, retry_seed
+                        ));
+                        seed = retry_seed;
+                    }
+                    _ => break outcome,
+                }
+            };
+            log.tick(func.name(), dim);
+
+            let res = match outcome {
+                Some(Ok(res)) => res,
+                None => {
+                    log.line(format!(
+                        "    Run {} (seed {}) timed out after {}s",
+                        i,
+                        seed,
+                        cli.timeout_sec.unwrap_or_default()
+                    ));
+                    timeouts += 1;
+                    continue;
+                }
+                Some(Err(e)) => {
+                    if cli.aborts_on(&e) {
+                        log.flush();
+                        eprintln!(
+                            "{} (dim {}) run {} (seed {}) failed: {}",
+                            func.name(),
+                            dim,
+                            i,
+                            seed,
+                            e
+                        );
+                        std::process::exit(1);
+                    }
+                    log.line(format!("    Run {} (seed {}) failed: {}", i, seed, e));
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            runs.push(RunRecord {
+                seed,
+                runtime_sec: res.runtime.as_secs_f64(),
+                cpu_sec: res.cpu_time.map(|t| t.as_secs_f64()),
+                // The peak is process-wide, so concurrent functions would inflate each other's
+                peak_memory_bytes: res.peak_memory_bytes.filter(|_| !cli.parallel_functions),
+                stage1_sec: res.stage1_runtime.as_secs_f64(),
+                stage2_sec: res.stage2_runtime.as_secs_f64(),
+                solution_set_size: res.solution_set_size,
+                near_optimal: cli.success_metric.count_successes(
+                    func.as_ref(),
+                    dim,
+                    &res.solution_objectives,
+                    tolerance,
+                ),
+                min_pairwise_distance: res.min_pairwise_distance,
+                best_obj: res.best_obj,
+                evaluations: res.function_evaluations,
+                success: cli.success_metric.is_success(func.as_ref(), dim, res.best_obj, tolerance),
+                distance: opts
+                    .nearest_argmin(func.as_ref(), dim, &res.best_point)
+                    .map(|opt| euclidean_distance(&res.best_point, &opt)),
+                convergence: if cli.plot_convergence {
+                    res.convergence.iter().map(|obj| sign * (obj - global_minimum)).collect()
+                } else {
+                    Vec::new()
+                },
+                budget_exhausted: res.budget_exhausted,
+                best_point: if cli.dump_points { res.best_point.to_vec() } else { Vec::new() },
+            });
+        }
+
+        let dim_runs = DimRuns { dim, runs, failed, timeouts };
+        match aggregate(&dim_runs, cli.trim_fraction) {
+            Some(s) => log.line(run_summary(&s, &dim_runs, cli)),
+            None => log.line(format!(
+                "    No completed runs (Failed: {}, Timeouts: {})",
+                dim_runs.failed, dim_runs.timeouts
+            )),
+        }
+        if let Some(checkpoint) = checkpoint {
+            checkpoint.record(func.name(), &dim_runs);
+        }
+        results.push(dim_runs);
+    }
+
+    results
+}
+
+/// Summary line printed after the runs of one dimension.
+fn run_summary(s: &StatPoint, dim_runs: &DimRuns, cli: &Cli) -> String {
+    let mut summary = format!("    SR: {:.2}", s.success_rate);
+    if cli.timing.wall() {
+        summary.push_str(&format!(
+            ", Avg T: {:.4}s (trimmed {:.4}s)",
+            s.avg_runtime_sec, s.trimmed_mean_runtime_sec
+        ));
+    }
+    if cli.timing.cpu() {
+        match s.avg_cpu_sec {
+            Some(cpu) => summary.push_str(&format!(", Avg CPU: {:.4}s", cpu)),
+            None => summary.push_str(", Avg CPU: n/a"),
+        }
+    }
+    summary.push_str(&format!(
+        ", Avg SolSize: {:.1}, Avg Evals: {:.0}",
+        s.avg_solution_set_size, s.avg_evaluations
+    ));
+    if s.failed_runs > 0 {
+        summary.push_str(&format!(", Failed: {}", s.failed_runs));
+    }
+    if s.timeouts > 0 {
+        summary.push_str(&format!(", Timeouts: {}", s.timeouts));
+    }
+    if s.runtime_outliers > 0 {
+        summary.push_str(&format!(", Runtime outliers: {}", s.runtime_outliers));
+    }
+    let exhausted = dim_runs.runs.iter().filter(|r| r.budget_exhausted).count();
+    if exhausted > 0 {
+        summary.push_str(&format!(", Budget exhausted: {}", exhausted));
+    }
+    if cli.ci_target.is_some() {
+        summary.push_str(&format!(", Runs: {}", s.runs_used));
+    }
+    summary
+}
+
+/// A single run picked from a raw dump for --only-failures.
+struct FailedRun {
+    func_name: String,
+    dim: usize,
+    // Seed actually passed to OQNLP, so no dimension mixing is applied again
+    seed: u64,
+}
+
+/// The completed but unsuccessful runs of a raw dump. Failed and timed-out runs
+/// are only counted in the dump, so their seeds are unknown and they can't be rerun.
+fn failed_runs(raw_runs: &BTreeMap<String, Vec<DimRuns>>) -> Vec<FailedRun> {
+    raw_runs
+        .iter()
+        .flat_map(|(func_name, per_dim)| {
+            per_dim.iter().flat_map(move |dim_runs| {
+                dim_runs.runs.iter().filter(|r| !r.success).map(move |r| FailedRun {
+                    func_name: func_name.clone(),
+                    dim: dim_runs.dim,
+                    seed: r.seed,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Reruns each of `failures` once with OQNLP's verbose output and reports the outcome.
+fn rerun_failures(failures: &[FailedRun], functions: &[Arc<dyn BenchmarkFn>], cli: &Cli) {
+    let params = cli.oqnlp_params();
+    for failure in failures {
+        let Some(func) = functions.iter().find(|f| f.name() == failure.func_name) else {
+            eprintln!(
+                "Warning: {} is not among the selected functions, skipping its run (seed {})",
+                failure.func_name, failure.seed
+            );
+            continue;
+        };
+        println!("{} (dim {}) seed {}:", func.name(), failure.dim, failure.seed);
+        let opts =
+            RunOptions { maximize: func.is_maximization(), verbose: true, ..cli.run_options() };
+        match func.run(failure.dim, failure.seed, &params, &opts) {
+            Ok(res) => {
+                let tolerance = cli.tolerance.unwrap_or_else(|| func.success_tolerance());
+                let success = cli.success_metric.is_success(
+                    func.as_ref(),
+                    failure.dim,
+                    res.best_obj,
+                    tolerance,
+                );
+                println!(
+                    "  Best objective: {} (optimum {}), {}",
+                    res.best_obj,
+                    func.global_minimum(failure.dim),
+                    if success { "now successful" } else { "still unsuccessful" }
+                );
+                println!("  Best point: {}", res.best_point);
+            }
+            Err(e) => println!("  Failed: {}", e),
+        }
+    }
+}
+
+/// Stats of every function once per swept parameter value, keyed by function name.
+type SweepCurves = BTreeMap<String, Vec<(usize, Vec<StatPoint>)>>;
+
+/// Benchmarks every function once per value of `sweep`, keeping the aggregated stats.
+/// Each value is applied to the OQNLP parameters of `cli` before its pass.
+fn run_sweep(
+    sweep: &Sweep,
+    functions: &[Arc<dyn BenchmarkFn>],
+    default_dims: &[usize],
+    seeds: &[u64],
+    warmup_seeds: &[u64],
+    cli: &mut Cli,
+) -> SweepCurves {
+    let base_params = cli.oqnlp_params();
+    let mut curves = SweepCurves::new();
+    for &value in &sweep.values {
+        println!("Sweep: {} = {}", sweep.param.name(), value);
+        let params = sweep.apply(&base_params, value);
+        cli.population_size = Some(params.population_size);
+        cli.iterations = Some(params.iterations);
+        for func in functions {
+            let mut log = ProgressLog::live(None);
+            let runs =
+                benchmark_function(func, default_dims, seeds, warmup_seeds, cli, None, &mut log);
+            let stats = runs.iter().filter_map(|d| aggregate(d, cli.trim_fraction)).collect();
+            curves.entry(func.name().to_string()).or_default().push((value, stats));
+        }
+    }
+    curves
+}
+
+/// Every benchmark function, in the order they are run and listed.
+fn all_functions(xin_she_yang: XinSheYangVariant) -> Vec<Arc<dyn BenchmarkFn>> {
+    vec![
+        Arc::new(Rosenbrock),
+        Arc::new(Rastrigin),
+        Arc::new(Ackley),
+        Arc::new(Griewank),
+        Arc::new(Levy),
+        Arc::new(SixHumpCamel),
+        Arc::new(CrossInTray),
+        Arc::new(Sphere),
+        Arc::new(Schwefel),
+        Arc::new(Michalewicz),
+        Arc::new(StyblinskiTang),
+        Arc::new(Zakharov),
+        Arc::new(DixonPrice),
+        Arc::new(GoldsteinPrice),
+        Arc::new(Booth),
+        Arc::new(Beale),
+        Arc::new(Eggholder),
+        Arc::new(HolderTable),
+        Arc::new(RotatedHyperEllipsoid),
+        Arc::new(SumSquares),
+        Arc::new(SumDiffPowers),
+        Arc::new(LevyN13),
+        Arc::new(ThreeHumpCamel),
+        Arc::new(Trid),
+        Arc::new(Shekel { m: ShekelVariant::M5 }),
+        Arc::new(Shekel { m: ShekelVariant::M7 }),
+        Arc::new(Shekel::default()),
+        Arc::new(McCormick),
+        Arc::new(Matyas),
+        Arc::new(BukinN6),
+        Arc::new(Branin),
+        Arc::new(SchafferN2),
+        Arc::new(SchafferN4),
+        Arc::new(Perm0::default()),
+        Arc::new(PermDB::default()),
+        Arc::new(DropWave),
+        Arc::new(Easom),
+        Arc::new(Langermann),
+        Arc::new(Bohachevsky { variant: BohachevskyVariant::N1 }),
+        Arc::new(Bohachevsky { variant: BohachevskyVariant::N2 }),
+        Arc::new(Bohachevsky { variant: BohachevskyVariant::N3 }),
+        Arc::new(AckleyN2),
+        Arc::new(AckleyN4),
+        Arc::new(Salomon),
+        Arc::new(AlpineN1),
+        Arc::new(Qing),
+        Arc::new(ChungReynolds),
+        Arc::new(XinSheYang { variant: xin_she_yang }),
+    ]
+}
+
+/// Keeps the functions named in `names` (case-insensitively), in their usual
+/// order. Unknown names are an error suggesting the closest known name.
+fn select_functions(
+    all_functions: Vec<Arc<dyn BenchmarkFn>>,
+    names: &[String],
+) -> Result<Vec<Arc<dyn BenchmarkFn>>, String> {
+    let wanted: BTreeSet<String> = names.iter().map(|name| name.trim().to_lowercase()).collect();
+    for name in &wanted {
+        if !all_functions.iter().any(|f| f.name().to_lowercase() == *name) {
+            let closest = all_functions
+                .iter()
+                .map(|f| (edit_distance(name, &f.name().to_lowercase()), f.name()))
+                .min()
+                .filter(|&(distance, _)| distance <= 3);
+            return Err(match closest {
+                Some((_, suggestion)) => {
+                    format!("unknown function '{}' (did you mean '{}'?)", name, suggestion)
+                }
+                None => format!("unknown function '{}' (see --list-functions)", name),
+            });
+        }
+    }
+    Ok(all_functions.into_iter().filter(|f| wanted.contains(&f.name().to_lowercase())).collect())
+}
+
+/// Keeps the functions carrying `tag`, one of [`TAGS`].
+fn with_tag(functions: Vec<Arc<dyn BenchmarkFn>>, tag: &str) -> Vec<Arc<dyn BenchmarkFn>> {
+    functions.into_iter().filter(|f| f.tags().contains(&tag)).collect()
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            row.push(substitution.min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+/// Prints one line per function: name, dimensions, the global minimum at each
+/// dimension and tags, separated by tabs with comma-separated lists.
+fn print_functions(functions: &[Arc<dyn BenchmarkFn>], default_dims: &[usize]) {
+    for func in functions {
+        let dims = func.supported_dims(default_dims);
+        let join = |values: Vec<String>| values.join(",");
+        println!(
+            "{}\t{}\t{}\t{}",
+            func.name(),
+            join(dims.iter().map(|d| d.to_string()).collect()),
+            join(dims.iter().map(|&d| func.global_minimum(d).to_string()).collect()),
+            func.tags().join(",")
+        );
+    }
+}
+
+/// Prints the benchmark matrix that a real invocation would execute.
+fn print_plan(
+    functions: &[Arc<dyn BenchmarkFn>],
+    default_dims: &[usize],
+    seeds: &[u64],
+    warmup_seeds: &[u64],
+    params: &OQNLPParams,
+    opts: &RunOptions,
+    seed_mixes_dim: bool,
+) {
+    println!("Benchmark plan:");
+    let mut total_configs = 0;
+    for func in functions {
+        let dims = func.supported_dims(default_dims);
+        total_configs += dims.len();
+        println!("  {}: dims {:?}", func.name(), dims);
+    }
+    println!("Seeds ({}): {:?}", seeds.len(), seeds);
+    if seed_mixes_dim {
+        println!("  (mixed with the dimension of each run)");
+    }
+    if !warmup_seeds.is_empty() {
+        println!("Warmup seeds ({}): {:?}", warmup_seeds.len(), warmup_seeds);
+    }
+    println!(
+        "Params: iterations = {}, population_size = {}, wait_cycle = {}, threshold_factor = {}, \
+         distance_factor = {}, local_solver = {:?}",
+        params.iterations,
+        params.population_size,
+        params.wait_cycle,
+        params.threshold_factor,
+        params.distance_factor,
+        params.local_solver_type
+    );
+    if opts.noise_sigma > 0.0 {
+        println!("Noise: sigma = {}", opts.noise_sigma);
+    }
+    if opts.shift != 0.0 {
+        println!("Shift: optima moved by {} along each axis", opts.shift);
+    }
+    if opts.rotate {
+        println!("Rotation: fixed random rotation per dimension");
+    }
+    if opts.bounds_scale != 1.0 {
+        println!("Bounds: width scaled by {} around the center", opts.bounds_scale);
+    }
+    println!(
+        "Total: {} functions, {} (function, dim) pairs, {} measured runs (+{} warmup)",
+        functions.len(),
+        total_configs,
+        total_configs * seeds.len(),
+        total_configs * warmup_seeds.len()
+    );
+}
+
+/// Runs a single benchmark, giving up after `timeout_sec` if set. Returns `None` on timeout.
+/// A timed-out run is cancelled and waited for, so that it can't keep using CPU and
+/// memory while the next run is measured.
+fn run_with_timeout(
+    func: &Arc<dyn BenchmarkFn>,
+    dim: usize,
+    seed: u64,
+    params: &OQNLPParams,
+    opts: &RunOptions,
+    timeout_sec: Option<f64>,
+) -> Option<Result<RunResult, BenchError>> {
+    let Some(timeout_sec) = timeout_sec else {
+        return Some(func.run(dim, seed, params, opts));
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let func = Arc::clone(func);
+    let params = params.clone();
+    let opts = RunOptions { cancel: Some(Arc::clone(&cancel)), ..opts.clone() };
+    let worker = thread::spawn(move || {
+        // The receiver is gone if the run timed out
+        let _ = tx.send(func.run(dim, seed, &params, &opts));
+    });
+    match rx.recv_timeout(Duration::from_secs_f64(timeout_sec)) {
+        Ok(outcome) => Some(outcome),
+        Err(_) => {
+            // The run fails at its next objective evaluation and returns soon after
+            cancel.store(true, Ordering::Relaxed);
+            let _ = worker.join();
+            None
+        }
+    }
+}
+
+/// Statistics of the completed runs at one dimension, or `None` if every run
+/// failed or timed out, since the averages would all be NaN.
+fn aggregate(dim_runs: &DimRuns, trim_fraction: f64) -> Option<StatPoint> {
+    let runs = &dim_runs.runs;
+    if runs.is_empty() {
+        return None;
+    }
+    let collect = |f: fn(&RunRecord) -> f64| runs.iter().map(f).collect::<Vec<f64>>();
+
+    let runtimes = collect(|r| r.runtime_sec);
+    let solution_set_sizes = collect(|r| r.solution_set_size as f64);
+    let distances: Vec<f64> = runs.iter().filter_map(|r| r.distance).collect();
+    let pairwise_distances: Vec<f64> =
+        runs.iter().filter_map(|r| r.min_pairwise_distance).collect();
+    let convergence_curves: Vec<Vec<f64>> = runs.iter().map(|r| r.convergence.clone()).collect();
+
+    let avg_runtime = mean(&runtimes);
+    let std_runtime = std_dev(&runtimes, avg_runtime);
+    let avg_sol_size = mean(&solution_set_sizes);
+    let avg_stage1 = mean(&collect(|r| r.stage1_sec));
+    let avg_stage2 = mean(&collect(|r| r.stage2_sec));
+    let cpu_times: Vec<f64> = runs.iter().filter_map(|r| r.cpu_sec).collect();
+    let peak_memory: Vec<f64> =
+        runs.iter().filter_map(|r| r.peak_memory_bytes).map(|b| b as f64).collect();
+    let successes = runs.iter().filter(|r| r.success).count();
+    let trials = runs.len() + dim_runs.failed + dim_runs.timeouts;
+    let (success_rate_ci_low, success_rate_ci_high) = wilson_interval(successes, trials);
+
+    Some(StatPoint {
+        dim: dim_runs.dim,
+        success_rate: successes as f64 / trials as f64,
+        success_rate_ci_low,
+        success_rate_ci_high,
+        avg_runtime_sec: avg_runtime,
+        std_runtime_sec: std_runtime,
+        ci95_runtime_sec: 1.96 * std_runtime / (runs.len() as f64).sqrt(),
+        trimmed_mean_runtime_sec: trimmed_mean(&runtimes, trim_fraction),
+        runtime_outliers: iqr_outliers(&runtimes),
+        avg_cpu_sec: (!cpu_times.is_empty()).then(|| mean(&cpu_times)),
+        avg_peak_memory_bytes: (!peak_memory.is_empty()).then(|| mean(&peak_memory)),
+        avg_stage1_sec: avg_stage1,
+        avg_stage2_sec: avg_stage2,
+        stage1_fraction: time_fraction(avg_stage1, avg_runtime),
+        stage2_fraction: time_fraction(avg_stage2, avg_runtime),
+        avg_solution_set_size: avg_sol_size,
+        std_solution_set_size: std_dev(&solution_set_sizes, avg_sol_size),
+        avg_best_obj: mean(&collect(|r| r.best_obj)),
+        avg_evaluations: mean(&collect(|r| r.evaluations as f64)),
+        failed_runs: dim_runs.failed,
+        timeouts: dim_runs.timeouts,
+        runs_used: runs.len() + dim_runs.failed + dim_runs.timeouts,
+        avg_solution_distance: (!distances.is_empty()).then(|| mean(&distances)),
+        avg_near_optimal: Some(mean(&collect(|r| r.near_optimal as f64))),
+        avg_min_pairwise_distance: (!pairwise_distances.is_empty())
+            .then(|| mean(&pairwise_distances)),
+        avg_convergence: mean_convergence(&convergence_curves),
+        raw_runtimes: runtimes,
+    })
+}
+
+/// Half-width of the 95% Wilson score interval for a success rate.
+fn success_ci_half_width(successes: usize, trials: usize) -> f64 {
+    if trials == 0 {
+        return f64::INFINITY;
+    }
+    let (low, high) = wilson_interval(successes, trials);
+    (high - low) / 2.0
+}
+
+// Zero rather than NaN/inf when no time was measured
+fn time_fraction(part: f64, total: f64) -> f64 {
+    if total > 0.0 { part / total } else { 0.0 }
+}
+
+/// Fits `time ≈ a * dim^b` by least squares on log-log data, returning `(a, b, r2)`.
+/// Needs at least two distinct dimensions with positive runtimes.
+fn fit_power_law(dims: &[f64], times: &[f64]) -> Option<(f64, f64, f64)> {
+    let (xs, ys): (Vec<f64>, Vec<f64>) = dims
+        .iter()
+        .zip(times)
+        .filter(|&(&d, &t)| d > 0.0 && t > 0.0)
+        .map(|(d, t)| (d.ln(), t.ln()))
+        .unzip();
+    let x_mean = mean(&xs);
+    let y_mean = mean(&ys);
+    let sxx: f64 = xs.iter().map(|x| (x - x_mean).powi(2)).sum();
+    if xs.len() < 2 || sxx == 0.0 {
+        return None;
+    }
+    let sxy: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - x_mean) * (y - y_mean)).sum();
+    let b = sxy / sxx;
+    let ln_a = y_mean - b * x_mean;
+
+    let ss_tot: f64 = ys.iter().map(|y| (y - y_mean).powi(2)).sum();
+    let ss_res: f64 = xs.iter().zip(&ys).map(|(x, y)| (y - ln_a - b * x).powi(2)).sum();
+    let r2 = if ss_tot > 0.0 { 1.0 - ss_res / ss_tot } else { 1.0 };
+    Some((ln_a.exp(), b, r2))
+}
+
+fn runtime_fit(stats: &[StatPoint]) -> Option<(f64, f64, f64)> {
+    let dims: Vec<f64> = stats.iter().map(|s| s.dim as f64).collect();
+    let times: Vec<f64> = stats.iter().map(|s| s.avg_runtime_sec).collect();
+    fit_power_law(&dims, &times)
+}
+
+fn print_runtime_scaling(stats: &AllStats) {
+    let func_names: Vec<&String> = stats.data.keys().collect();
+
+    let fits: Vec<(&String, (f64, f64, f64))> = func_names
+        .into_iter()
+        .filter_map(|name| runtime_fit(&stats.data[name]).map(|fit| (name, fit)))
+        .collect();
+    if fits.is_empty() {
+        return;
+    }
+    println!("Runtime scaling (runtime ≈ a * dim^b):");
+    for (name, (a, b, r2)) in fits {
+        println!("  {}: b = {:.3}, a = {:.3e}, R² = {:.3}", name, b, a, r2);
+    }
+}
+
+/// Composite score of one result, see `ScoreWeights::score`.
+fn score(s: &StatPoint, weights: &ScoreWeights) -> f64 {
+    weights.score(s.success_rate, s.avg_runtime_sec)
+}
+
+/// Prints the functions ranked by their score, with the overall geometric mean.
+fn print_leaderboard(stats: &AllStats, weights: &ScoreWeights) {
+    let board = leaderboard(
+        stats
+            .data
+            .iter()
+            .map(|(name, points)| {
+                (name.clone(), points.iter().map(|s| score(s, weights)).collect())
+            })
+            .collect(),
+    );
+    if board.is_empty() {
+        return;
+    }
+    println!("Leaderboard (score = SR^{} / runtime^{}):", weights.sr, weights.time);
+    for (rank, (name, score)) in board.iter().enumerate() {
+        println!("  {:>2}. {:<24} {:.4}", rank + 1, name, score);
+    }
+    match overall_score(&board) {
+        Some((overall, n)) => {
+            println!("  Overall (geometric mean over {} functions): {:.4}", n, overall)
+        }
+        None => println!("  Overall: no function succeeded"),
+    }
+}
+
+/// Runtime ratios per function, as (dimension, ratio) pairs.
+type RuntimeRatios = BTreeMap<String, Vec<(usize, f64)>>;
+
+/// Mean runtime of every function divided by that of `reference` (matched
+/// case-insensitively) at the same dimension. Dimensions the reference wasn't run
+/// at are left out.
+fn normalized_runtimes(stats: &AllStats, reference: &str) -> Result<RuntimeRatios, String> {
+    let reference_points = stats
+        .data
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(reference))
+        .map(|(_, points)| points)
+        .ok_or_else(|| format!("{} was not run", reference))?;
+    Ok(stats
+        .data
+        .iter()
+        .map(|(name, points)| {
+            let ratios = points
+                .iter()
+                .filter_map(|p| {
+                    let base = reference_points.iter().find(|r| r.dim == p.dim)?;
+                    (base.avg_runtime_sec > 0.0)
+                        .then_some((p.dim, p.avg_runtime_sec / base.avg_runtime_sec))
+                })
+                .collect();
+            (name.clone(), ratios)
+        })
+        .collect())
+}
+
+fn print_normalized_runtimes(ratios: &RuntimeRatios, reference: &str) {
+    println!("Runtime relative to {}:", reference);
+    for (name, points) in ratios.iter().filter(|(_, points)| !points.is_empty()) {
+        let points: Vec<String> =
+            points.iter().map(|(dim, ratio)| format!("dim {}: {:.2}x", dim, ratio)).collect();
+        println!("  {}: {}", name, points.join(", "));
+    }
+}
+
+fn mean(data: &[f64]) -> f64 {
+    let sum: f64 = data.iter().sum();
+    sum / data.len() as f64
+}
+
+fn std_dev(data: &[f64], mean: f64) -> f64 {
+    let variance = data
+        .iter()
+        .map(|value| {
+            let diff = mean - *value;
+            diff * diff
+        })
+        .sum::<f64>()
+        / data.len() as f64;
+    variance.sqrt()
+}
+
+fn stats_to_csv(stats: &AllStats) -> String {
+    let mut csv = String::from(
+        "function,dim,success_rate,success_rate_ci_low,success_rate_ci_high,avg_runtime_sec,std_runtime_sec,ci95_runtime_sec,trimmed_mean_runtime_sec,runtime_outliers,avg_cpu_sec,avg_peak_memory_bytes,avg_stage1_sec,avg_stage2_sec,stage1_fraction,stage2_fraction,\
+         avg_solution_set_size,std_solution_set_size,avg_best_obj,avg_evaluations,failed_runs,timeouts,runs_used,avg_solution_distance,\
+         avg_near_optimal,avg_min_pairwise_distance\n",
+    );
+    for (func_name, points) in &stats.data {
+        for s in points {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                func_name,
+                s.dim,
+                s.success_rate,
+                s.success_rate_ci_low,
+                s.success_rate_ci_high,
+                s.avg_runtime_sec,
+                s.std_runtime_sec,
+                s.ci95_runtime_sec,
+                s.trimmed_mean_runtime_sec,
+                s.runtime_outliers,
+                s.avg_cpu_sec.map(|t| t.to_string()).unwrap_or_default(),
+                s.avg_peak_memory_bytes.map(|b| b.to_string()).unwrap_or_default(),
+                s.avg_stage1_sec,
+                s.avg_stage2_sec,
+                s.stage1_fraction,
+                s.stage2_fraction,
+                s.avg_solution_set_size,
+                s.std_solution_set_size,
+                s.avg_best_obj,
+                s.avg_evaluations,
+                s.failed_runs,
+                s.timeouts,
+                s.runs_used,
+                s.avg_solution_distance.map(|d| d.to_string()).unwrap_or_default(),
+                s.avg_near_optimal.map(|n| n.to_string()).unwrap_or_default(),
+                s.avg_min_pairwise_distance.map(|d| d.to_string()).unwrap_or_default()
+            ));
+        }
+    }
+    csv
+}
+
+/// Per-run table with the `problem, dimension, f_best, f_opt, gap, time, evals, status`
+/// columns used by common optimization benchmark tools. Failed and timed-out runs get a
+/// `failed` row with the values left empty, as does `f_opt` for functions not in `functions`.
+fn results_table(
+    raw_runs: &BTreeMap<String, Vec<DimRuns>>,
+    functions: &[Arc<dyn BenchmarkFn>],
+) -> String {
+    let mut table = String::from("problem,dimension,f_best,f_opt,gap,time,evals,status\n");
+    for (func_name, per_dim) in raw_runs {
+        let func = functions.iter().find(|f| f.name() == func_name);
+        for dim_runs in per_dim {
+            let f_opt = func.map(|f| f.global_minimum(dim_runs.dim));
+            for r in &dim_runs.runs {
+                table.push_str(&format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    func_name,
+                    dim_runs.dim,
+                    r.best_obj,
+                    f_opt.map(|f| f.to_string()).unwrap_or_default(),
+                    f_opt.map(|f| (r.best_obj - f).to_string()).unwrap_or_default(),
+                    r.runtime_sec,
+                    r.evaluations,
+                    if r.success { "optimal" } else { "suboptimal" }
+                ));
+            }
+            for _ in 0..dim_runs.failed + dim_runs.timeouts {
+                table.push_str(&format!("{},{},,,,,,failed\n", func_name, dim_runs.dim));
+            }
+        }
+    }
+    table
+}
+
+fn stats_to_markdown(current: &AllStats, baseline: Option<&AllStats>) -> String {
+    let func_names: Vec<&String> = current.data.keys().collect();
+
+    let mut md = String::new();
+    for func_name in func_names {
+        let base_points = baseline.and_then(|b| b.data.get(func_name));
+
+        md.push_str(&format!("### {}\n\n", func_name));
+        md.push_str("| Dim | Success Rate | Avg Runtime (s) | Avg SolSize | Avg Best Obj |");
+        if base_points.is_some() {
+            md.push_str(" Δ SR | Δ Runtime (s) | p (Runtime) |");
+        }
+        md.push('\n');
+        md.push_str("|---:|---:|---:|---:|---:|");
+        if base_points.is_some() {
+            md.push_str("---:|---:|---:|");
+        }
+        md.push('\n');
+
+        for s in &current.data[func_name] {
+            md.push_str(&format!(
+                "| {} | {:.2} | {:.4} | {:.1} | {:.6e} |",
+                s.dim, s.success_rate, s.avg_runtime_sec, s.avg_solution_set_size, s.avg_best_obj
+            ));
+            if let Some(points) = base_points {
+                match points.iter().find(|b| b.dim == s.dim) {
+                    Some(b) => md.push_str(&format!(
+                        " {:+.2} | {:+.4} | {} |",
+                        s.success_rate - b.success_rate,
+                        s.avg_runtime_sec - b.avg_runtime_sec,
+                        match ttest(&s.raw_runtimes, &b.raw_runtimes) {
+                            Some(t) if t.is_significant(ALPHA) => format!("{:.3} *", t.p_value),
+                            Some(t) => format!("{:.3}", t.p_value),
+                            None => "-".to_string(),
+                        }
+                    )),
+                    None => md.push_str(" - | - | - |"),
+                }
+            }
+            md.push('\n');
+        }
+        md.push('\n');
+    }
+    md
+}
+
+/// Prints Welch's t-test results for the runtime difference against the baseline
+/// for every (function, dim) present in both runs.
+fn print_runtime_significance(current: &AllStats, label: &str, baseline: &AllStats) {
+    let func_names: Vec<&String> = current.data.keys().collect();
+
+    println!("Runtime vs baseline {} (Welch's t-test, α = {}):", label, ALPHA);
+    for func_name in func_names {
+        let Some(base_points) = baseline.data.get(func_name) else {
+            continue;
+        };
+        for s in &current.data[func_name] {
+            let Some(b) = base_points.iter().find(|b| b.dim == s.dim) else {
+                continue;
+            };
+            match ttest(&s.raw_runtimes, &b.raw_runtimes) {
+                Some(t) => println!(
+                    "  {} (dim {}): Δ {:+.4}s, t = {:.3}, df = {:.1}, p = {:.4}{}",
+                    func_name,
+                    s.dim,
+                    s.avg_runtime_sec - b.avg_runtime_sec,
+                    t.t,
+                    t.df,
+                    t.p_value,
+                    if t.is_significant(ALPHA) { " (significant)" } else { "" }
+                ),
+                None => println!("  {} (dim {}): not enough per-run data", func_name, s.dim),
+            }
+        }
+    }
+}
+
+/// Averages per-run optimality-gap histories into a single curve.
+/// Runs that stopped early keep contributing their final value.
+fn mean_convergence(curves: &[Vec<f64>]) -> Vec<f64> {
+    let len = curves.iter().map(|c| c.len()).max().unwrap_or(0);
+    let curves: Vec<&Vec<f64>> = curves.iter().filter(|c| !c.is_empty()).collect();
+    (0..len)
+        .map(|i| {
+            let gaps: Vec<f64> = curves.iter().map(|c| c[i.min(c.len() - 1)]).collect();
+            mean(&gaps)
+        })
+        .collect()
+}
+
+fn euclidean_distance(a: &Array1<f64>, b: &Array1<f64>) -> f64 {
+    (a - b).mapv(|d| d * d).sum().sqrt()
+}
+
+/// Half-width of the runtime error bar for the selected statistic.
+fn runtime_error(s: &StatPoint, error_bars: ErrorBars) -> f64 {
+    match error_bars {
+        ErrorBars::Std => s.std_runtime_sec,
+        ErrorBars::Ci => s.ci95_runtime_sec,
+        ErrorBars::Sem => s.ci95_runtime_sec / 1.96,
+    }
+}
+
+/// Colors cycled through for the current results and each baseline.
+const SERIES_COLORS: [NamedColor; 8] = [
+    NamedColor::RoyalBlue,
+    NamedColor::OrangeRed,
+    NamedColor::SeaGreen,
+    NamedColor::MediumPurple,
+    NamedColor::Goldenrod,
+    NamedColor::Teal,
+    NamedColor::Crimson,
+    NamedColor::SaddleBrown,
+];
+
+fn generate_plots(
+    func_name: &str,
+    current: &[StatPoint],
+    baselines: &[(&str, &[StatPoint])],
+    timing: Timing,
+    error_bars: ErrorBars,
+    reference: Option<ReferenceCurve>,
+    format: PlotFormat,
+) {
+    let x_vals: Vec<usize> = current.iter().map(|s| s.dim).collect();
+    let runtime_axis_title = match runtime_fit(current) {
+        Some((_, b, r2)) => format!("Time (s), ∝ dim^{:.2} (R² = {:.2})", b, r2),
+        None => "Time (s)".to_string(),
+    };
+
+    let mut plot = Plot::new();
+
+    let layout = Layout::new()
+        .title(Title::with_text(format!("{} Benchmarks", func_name)))
+        .grid(LayoutGrid::new().rows(4).columns(1).pattern(GridPattern::Independent))
+        // Success Rate
+        .x_axis(Axis::new().title(Title::with_text("Dimension")))
         .y_axis(Axis::new().title(Title::with_text("Success Rate")))
         // Runtime
         .x_axis2(Axis::new().title(Title::with_text("Dimension")))
-        .y_axis2(Axis::new().title(Title::with_text("Time (s)")))
+        .y_axis2(Axis::new().title(Title::with_text(runtime_axis_title)))
         // Solution Size
         .x_axis3(Axis::new().title(Title::with_text("Dimension")))
         .y_axis3(Axis::new().title(Title::with_text("Solution Set Size")))
-        .height(1200);
+        // Stage time fractions
+        .x_axis4(Axis::new().title(Title::with_text("Dimension")))
+        .y_axis4(Axis::new().title(Title::with_text("Fraction of Runtime")))
+        .bar_mode(BarMode::Stack)
+        .height(1600);
+
+    plot.set_layout(layout);
+
+    // Series are shifted sideways by a small fraction of the dimension spacing
+    // so that overlapping error bars stay distinguishable
+    let spacing =
+        x_vals.windows(2).map(|w| w[1].abs_diff(w[0]) as f64).fold(f64::INFINITY, f64::min);
+    let spacing = if spacing.is_finite() { spacing } else { 1.0 };
+    let series = std::iter::once(("Current", current)).chain(baselines.iter().copied());
+    let num_series = baselines.len() + 1;
+    for (i, (label, stats)) in series.enumerate() {
+        let offset = (i as f64 - (num_series - 1) as f64 / 2.0) * 0.04 * spacing;
+        let color = SERIES_COLORS[i % SERIES_COLORS.len()];
+        add_series(&mut plot, label, stats, offset, color, timing, error_bars);
+    }
+
+    if let Some(reference) = reference {
+        let (dims, times) = reference_runtimes(current, reference.exponent());
+        plot.add_trace(
+            Scatter::new(dims, times)
+                .name(format!("Reference {}", reference.label()))
+                .mode(Mode::Lines)
+                .line(Line::new().color(NamedColor::Gray).dash(DashType::Dash))
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+    }
+
+    let current_f1: Vec<f64> = current.iter().map(|s| s.stage1_fraction).collect();
+    let current_f2: Vec<f64> = current.iter().map(|s| s.stage2_fraction).collect();
+    plot.add_trace(
+        Bar::new(x_vals.clone(), current_f1).name("Stage 1 Fraction").x_axis("x4").y_axis("y4"),
+    );
+    plot.add_trace(
+        Bar::new(x_vals.clone(), current_f2).name("Stage 2 Fraction").x_axis("x4").y_axis("y4"),
+    );
+
+    write_plot(&plot, &format!("{}_benchmark", func_name.to_lowercase()), format);
+}
+
+/// Overlays the success rate, runtime and solution size of one function for every
+/// value of a `--sweep-param` sweep, written to `plots/<fn>_sweep_<param>.html`.
+fn generate_sweep_plot(
+    func_name: &str,
+    param: SweepParam,
+    curves: &[(usize, Vec<StatPoint>)],
+    timing: Timing,
+    error_bars: ErrorBars,
+    format: PlotFormat,
+) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} {} Sweep", func_name, param.name())))
+            .grid(LayoutGrid::new().rows(3).columns(1).pattern(GridPattern::Independent))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis(Axis::new().title(Title::with_text("Success Rate")))
+            .x_axis2(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis2(Axis::new().title(Title::with_text("Time (s)")))
+            .x_axis3(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis3(Axis::new().title(Title::with_text("Solution Set Size")))
+            .height(1200),
+    );
+
+    for (i, (value, stats)) in curves.iter().enumerate() {
+        let label = format!("{}={}", param.name(), value);
+        let color = SERIES_COLORS[i % SERIES_COLORS.len()];
+        add_series(&mut plot, &label, stats, 0.0, color, timing, error_bars);
+    }
+
+    write_plot(&plot, &format!("{}_sweep_{}", func_name.to_lowercase(), param.name()), format);
+}
+
+/// Runtime growing as `dim^exponent`, anchored at the mean runtime measured at the
+/// smallest dimension: `t(d) = t(d0) * (d / d0)^exponent`. Empty without results.
+fn reference_runtimes(stats: &[StatPoint], exponent: i32) -> (Vec<usize>, Vec<f64>) {
+    let Some(anchor) = stats.iter().min_by_key(|s| s.dim) else {
+        return (Vec::new(), Vec::new());
+    };
+    let mut dims: Vec<usize> = stats.iter().map(|s| s.dim).collect();
+    dims.sort_unstable();
+    let times = dims
+        .iter()
+        .map(|&d| anchor.avg_runtime_sec * (d as f64 / anchor.dim as f64).powi(exponent))
+        .collect();
+    (dims, times)
+}
+
+/// Adds the success rate, runtime and solution size traces of one result set,
+/// drawn in `color` and shifted by `offset` along the dimension axis.
+fn add_series(
+    plot: &mut Plot,
+    label: &str,
+    stats: &[StatPoint],
+    offset: f64,
+    color: NamedColor,
+    timing: Timing,
+    error_bars: ErrorBars,
+) {
+    let x_vals: Vec<f64> = stats.iter().map(|s| s.dim as f64 + offset).collect();
+    let trace = |y: Vec<f64>, name: &str, axis: usize| {
+        Scatter::new(x_vals.clone(), y)
+            .name(format!("{} {}", label, name))
+            .legend_group(label)
+            .mode(Mode::LinesMarkers)
+            .line(Line::new().color(color))
+            .marker(Marker::new().color(color))
+            .x_axis(if axis == 1 { "x".to_string() } else { format!("x{}", axis) })
+            .y_axis(if axis == 1 { "y".to_string() } else { format!("y{}", axis) })
+    };
+    let error = |array: Vec<f64>| {
+        ErrorData::new(ErrorType::Data).array(array).color(color).thickness(1.0).width(3)
+    };
+
+    // The upper bound is always positive, so zero means the interval was never recorded
+    let has_sr_ci = |s: &StatPoint| s.success_rate_ci_high > 0.0;
+    let sr_above: Vec<f64> = stats
+        .iter()
+        .map(|s| if has_sr_ci(s) { s.success_rate_ci_high - s.success_rate } else { 0.0 })
+        .collect();
+    let sr_below: Vec<f64> = stats
+        .iter()
+        .map(|s| if has_sr_ci(s) { s.success_rate - s.success_rate_ci_low } else { 0.0 })
+        .collect();
+    plot.add_trace(
+        trace(stats.iter().map(|s| s.success_rate).collect(), "SR", 1)
+            .error_y(error(sr_above).symmetric(false).array_minus(sr_below)),
+    );
+
+    if timing.wall() {
+        let runtime_err: Vec<f64> = stats.iter().map(|s| runtime_error(s, error_bars)).collect();
+        plot.add_trace(
+            trace(stats.iter().map(|s| s.avg_runtime_sec).collect(), "Total RT", 2)
+                .error_y(error(runtime_err)),
+        );
+    }
+    // Results saved without CPU times have nothing to plot
+    if timing.cpu() && stats.iter().any(|s| s.avg_cpu_sec.is_some()) {
+        let cpu: Vec<f64> = stats.iter().map(|s| s.avg_cpu_sec.unwrap_or(f64::NAN)).collect();
+        plot.add_trace(trace(cpu, "CPU Time", 2));
+    }
+    plot.add_trace(
+        trace(stats.iter().map(|s| s.avg_stage1_sec).collect(), "Stage 1 RT", 2)
+            .visible(Visible::LegendOnly),
+    );
+    plot.add_trace(
+        trace(stats.iter().map(|s| s.avg_stage2_sec).collect(), "Stage 2 RT", 2)
+            .visible(Visible::LegendOnly),
+    );
+
+    let std_sz: Vec<f64> = stats.iter().map(|s| s.std_solution_set_size).collect();
+    plot.add_trace(
+        trace(stats.iter().map(|s| s.avg_solution_set_size).collect(), "SolSize", 3)
+            .error_y(error(std_sz)),
+    );
+}
 
-    plot.set_layout(layout);
+fn generate_convergence_plot(func_name: &str, current: &[StatPoint], format: PlotFormat) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} Stage 2 Convergence", func_name)))
+            .x_axis(Axis::new().title(Title::with_text("Stage 2 Iteration")))
+            .y_axis(
+                Axis::new().title(Title::with_text("Mean Optimality Gap")).type_(AxisType::Log),
+            ),
+    );
 
-    let current_sr: Vec<f64> = current.iter().map(|s| s.success_rate).collect();
-    plot.add_trace(
-        Scatter::new(x_vals.clone(), current_sr)
-            .name("Current SR")
-            .mode(Mode::LinesMarkers)
-            .x_axis("x")
-            .y_axis("y"),
+    for s in current.iter().filter(|s| !s.avg_convergence.is_empty()) {
+        let iterations: Vec<usize> = (1..=s.avg_convergence.len()).collect();
+        // Clamp exact hits so they stay visible on the log axis
+        let gaps: Vec<f64> = s.avg_convergence.iter().map(|g| g.max(1e-16)).collect();
+        plot.add_trace(
+            Scatter::new(iterations, gaps).name(format!("Dim {}", s.dim)).mode(Mode::Lines),
+        );
+    }
+
+    write_plot(&plot, &format!("{}_convergence", func_name.to_lowercase()), format);
+}
+
+fn generate_boxplots(func_name: &str, per_run_data: &[DimRuns], format: PlotFormat) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} Runtime Distribution", func_name)))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis(Axis::new().title(Title::with_text("Time (s)"))),
     );
 
-    if let Some(base) = baseline {
-        let base_sr: Vec<f64> = base.iter().map(|s| s.success_rate).collect();
+    for dim_runs in per_run_data {
+        let runtimes: Vec<f64> = dim_runs.runs.iter().map(|r| r.runtime_sec).collect();
         plot.add_trace(
-            Scatter::new(x_vals.clone(), base_sr)
-                .name("Baseline SR")
-                .mode(Mode::LinesMarkers)
-                .x_axis("x")
-                .y_axis("y"),
+            BoxPlot::new(runtimes).name(format!("Dim {}", dim_runs.dim)).box_points(BoxPoints::All),
         );
     }
 
-    let current_rt: Vec<f64> = current.iter().map(|s| s.avg_runtime_sec).collect();
-    let current_std_rt: Vec<f64> = current.iter().map(|s| s.std_runtime_sec).collect();
-    let current_s1: Vec<f64> = current.iter().map(|s| s.avg_stage1_sec).collect();
-    let current_s2: Vec<f64> = current.iter().map(|s| s.avg_stage2_sec).collect();
+    write_plot(&plot, &format!("{}_runtime_box", func_name.to_lowercase()), format);
+}
 
-    plot.add_trace(
-        Scatter::new(x_vals.clone(), current_rt)
-            .name("Current Total RT")
-            .mode(Mode::LinesMarkers)
-            .error_y(ErrorData::new(ErrorType::Data).array(current_std_rt))
-            .x_axis("x2")
-            .y_axis("y2"),
+fn generate_objective_histograms(func_name: &str, per_run_data: &[DimRuns], format: PlotFormat) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} Best Objective Distribution", func_name)))
+            .grid(
+                LayoutGrid::new()
+                    .rows(per_run_data.len().max(1))
+                    .columns(1)
+                    .pattern(GridPattern::Independent),
+            )
+            .height(300 * per_run_data.len().max(1)),
+    );
+
+    for (i, dim_runs) in per_run_data.iter().enumerate() {
+        let best_objs: Vec<f64> = dim_runs.runs.iter().map(|r| r.best_obj).collect();
+        let Some(&first) = best_objs.first() else {
+            continue;
+        };
+        let (x_axis, y_axis) = if i == 0 {
+            ("x".to_string(), "y".to_string())
+        } else {
+            (format!("x{}", i + 1), format!("y{}", i + 1))
+        };
+        let mut histogram = Histogram::new(best_objs.clone())
+            .name(format!("Dim {}", dim_runs.dim))
+            .x_axis(&x_axis)
+            .y_axis(&y_axis);
+        if best_objs.iter().all(|&obj| obj == first) {
+            // Automatic binning collapses a zero-width range, so center one explicit bin on the value
+            let width = (first.abs() * 1e-3).max(1e-9);
+            histogram =
+                histogram.x_bins(Bins::new(first - width / 2.0, first + width / 2.0, width));
+        }
+        plot.add_trace(histogram);
+    }
+
+    write_plot(&plot, &format!("{}_obj_hist", func_name.to_lowercase()), format);
+}
+
+/// Violin trace, which plotly.rs does not provide. Only the attributes used by
+/// the benchmark plots are exposed.
+#[derive(Serialize, Clone)]
+struct Violin {
+    r#type: &'static str,
+    name: String,
+    y: Vec<f64>,
+    points: &'static str,
+    #[serde(rename = "box")]
+    box_: Visibility,
+    meanline: Visibility,
+}
+
+#[derive(Serialize, Clone)]
+struct Visibility {
+    visible: bool,
+}
+
+impl Violin {
+    /// Violin of `y` with the inner box, the mean line and every sample shown.
+    fn new(name: impl Into<String>, y: Vec<f64>) -> Box<Self> {
+        Box::new(Self {
+            r#type: "violin",
+            name: name.into(),
+            y,
+            points: "all",
+            box_: Visibility { visible: true },
+            meanline: Visibility { visible: true },
+        })
+    }
+}
+
+impl Trace for Violin {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+fn generate_objective_violins(func_name: &str, per_run_data: &[DimRuns], format: PlotFormat) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} Best Objective Distribution", func_name)))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis(Axis::new().title(Title::with_text("Best Objective"))),
+    );
+
+    for dim_runs in per_run_data {
+        let best_objs: Vec<f64> = dim_runs.runs.iter().map(|r| r.best_obj).collect();
+        let Some(&first) = best_objs.first() else {
+            continue;
+        };
+        let name = format!("Dim {}", dim_runs.dim);
+        if best_objs.iter().all(|&obj| obj == first) {
+            // A density estimate of a single value has zero bandwidth, so plot the point itself
+            plot.add_trace(
+                Scatter::new(vec![name.clone()], vec![first]).mode(Mode::Markers).name(name),
+            );
+        } else {
+            plot.add_trace(Violin::new(name, best_objs));
+        }
+    }
+
+    write_plot(&plot, &format!("{}_obj_violin", func_name.to_lowercase()), format);
+}
+
+fn generate_overview(all_stats: &AllStats, format: PlotFormat) {
+    let func_names: Vec<&String> = all_stats.data.keys().collect();
+
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text("Success Rate Overview"))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis(Axis::new().title(Title::with_text("Success Rate"))),
     );
 
+    for func_name in func_names {
+        let points = &all_stats.data[func_name];
+        let dims: Vec<usize> = points.iter().map(|s| s.dim).collect();
+        let success_rates: Vec<f64> = points.iter().map(|s| s.success_rate).collect();
+        // A line needs at least two points, e.g. 2D-only functions get a lone marker
+        let mode = if points.len() > 1 { Mode::LinesMarkers } else { Mode::Markers };
+        plot.add_trace(Scatter::new(dims, success_rates).name(func_name).mode(mode));
+    }
+
+    write_plot(&plot, "overview", format);
+}
+
+/// Heatmap trace with a fixed 0-1 color range. plotly.rs's `HeatMap` types
+/// `zmin`/`zmax` as a whole z row, so the range can't be set through it.
+#[derive(Serialize, Clone)]
+struct Heatmap {
+    r#type: &'static str,
+    name: String,
+    x: Vec<String>,
+    y: Vec<String>,
+    z: Vec<Vec<Option<f64>>>,
+    zmin: f64,
+    zmax: f64,
+    colorscale: Vec<(f64, String)>,
+    showscale: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hovertemplate: Option<String>,
+}
+
+impl Heatmap {
+    /// Heatmap of `z` (one row per `y` label) over the categorical `x` labels.
+    fn new(
+        name: impl Into<String>,
+        x: Vec<String>,
+        y: Vec<String>,
+        z: Vec<Vec<Option<f64>>>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            r#type: "heatmap",
+            name: name.into(),
+            x,
+            y,
+            z,
+            zmin: 0.0,
+            zmax: 1.0,
+            colorscale: Vec::new(),
+            showscale: true,
+            hovertemplate: None,
+        })
+    }
+
+    fn scale(mut self: Box<Self>, stops: &[(f64, &str)], show: bool) -> Box<Self> {
+        self.colorscale = stops.iter().map(|&(at, color)| (at, color.to_string())).collect();
+        self.showscale = show;
+        self
+    }
+
+    fn hover(mut self: Box<Self>, template: &str) -> Box<Self> {
+        self.hovertemplate = Some(template.to_string());
+        self
+    }
+}
+
+impl Trace for Heatmap {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// Heatmap of the success rate with one row per function and one column per dimension
+/// run by any function. Dimensions a function was not run at are drawn in gray as N/A.
+fn generate_success_heatmap(all_stats: &AllStats, format: PlotFormat) {
+    let dims: BTreeSet<usize> = all_stats.data.values().flatten().map(|p| p.dim).collect();
+    let func_names: Vec<&String> = all_stats.data.keys().collect();
+    let success_rates: Vec<Vec<Option<f64>>> = func_names
+        .iter()
+        .map(|name| {
+            let points = &all_stats.data[*name];
+            dims.iter()
+                .map(|&dim| points.iter().find(|p| p.dim == dim).map(|p| p.success_rate))
+                .collect()
+        })
+        .collect();
+    let missing: Vec<Vec<Option<f64>>> = success_rates
+        .iter()
+        .map(|row| row.iter().map(|sr| sr.is_none().then_some(1.0)).collect())
+        .collect();
+    // Categorical columns keep ragged dimension sets evenly spaced
+    let columns: Vec<String> = dims.iter().map(|d| d.to_string()).collect();
+    let rows: Vec<String> = func_names.iter().map(|name| name.to_string()).collect();
+
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text("Success Rate Heatmap"))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")).type_(AxisType::Category))
+            .y_axis(Axis::new().title(Title::with_text("Function")).type_(AxisType::Category))
+            .height(200 + 25 * rows.len()),
+    );
     plot.add_trace(
-        Scatter::new(x_vals.clone(), current_s1)
-            .name("Current Stage 1 RT")
-            .mode(Mode::LinesMarkers)
-            .visible(Visible::LegendOnly)
-            .x_axis("x2")
-            .y_axis("y2"),
+        Heatmap::new("Success Rate", columns.clone(), rows.clone(), success_rates)
+            .scale(&[(0.0, "#440154"), (0.5, "#21918c"), (1.0, "#fde725")], true)
+            .hover("%{y}, dim %{x}: %{z:.2}<extra></extra>"),
     );
+    // N/A cells are a separate single-color layer so they can't be mistaken for a zero rate
     plot.add_trace(
-        Scatter::new(x_vals.clone(), current_s2)
-            .name("Current Stage 2 RT")
-            .mode(Mode::LinesMarkers)
-            .visible(Visible::LegendOnly)
-            .x_axis("x2")
-            .y_axis("y2"),
+        Heatmap::new("N/A", columns, rows, missing)
+            .scale(&[(0.0, "lightgray"), (1.0, "lightgray")], false)
+            .hover("%{y}, dim %{x}: N/A<extra></extra>"),
     );
 
-    if let Some(base) = baseline {
-        let base_rt: Vec<f64> = base.iter().map(|s| s.avg_runtime_sec).collect();
-        let base_std_rt: Vec<f64> = base.iter().map(|s| s.std_runtime_sec).collect();
-        plot.add_trace(
-            Scatter::new(x_vals.clone(), base_rt)
-                .name("Baseline Total RT")
-                .mode(Mode::LinesMarkers)
-                .error_y(ErrorData::new(ErrorType::Data).array(base_std_rt))
-                .x_axis("x2")
-                .y_axis("y2"),
-        );
+    write_plot(&plot, "success_heatmap", format);
+}
 
-        let base_s1: Vec<f64> = base.iter().map(|s| s.avg_stage1_sec).collect();
-        let base_s2: Vec<f64> = base.iter().map(|s| s.avg_stage2_sec).collect();
-        plot.add_trace(
-            Scatter::new(x_vals.clone(), base_s1)
-                .name("Baseline Stage 1 RT")
-                .mode(Mode::LinesMarkers)
-                .visible(Visible::LegendOnly)
-                .x_axis("x2")
-                .y_axis("y2"),
-        );
-        plot.add_trace(
-            Scatter::new(x_vals.clone(), base_s2)
-                .name("Baseline Stage 2 RT")
-                .mode(Mode::LinesMarkers)
-                .visible(Visible::LegendOnly)
-                .x_axis("x2")
-                .y_axis("y2"),
-        );
+fn generate_normalized_runtime_plot(ratios: &RuntimeRatios, reference: &str, format: PlotFormat) {
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("Runtime Relative to {}", reference)))
+            .x_axis(Axis::new().title(Title::with_text("Dimension")))
+            .y_axis(
+                Axis::new()
+                    .title(Title::with_text(format!("Runtime / {} Runtime", reference)))
+                    .type_(AxisType::Log),
+            ),
+    );
+
+    for (name, points) in ratios.iter().filter(|(_, points)| !points.is_empty()) {
+        let (dims, values): (Vec<usize>, Vec<f64>) = points.iter().copied().unzip();
+        let mode = if dims.len() > 1 { Mode::LinesMarkers } else { Mode::Markers };
+        plot.add_trace(Scatter::new(dims, values).name(name).mode(mode));
+    }
+
+    write_plot(&plot, "normalized_runtime", format);
+}
+
+/// Empirical CDF of the time to success: for each successful runtime (ascending),
+/// the fraction of all runs that succeeded within it. Starts at (0, 0).
+fn success_ecdf(runtimes: &[f64], success: &[bool]) -> (Vec<f64>, Vec<f64>) {
+    let mut solved: Vec<f64> =
+        runtimes.iter().zip(success).filter(|(_, ok)| **ok).map(|(t, _)| *t).collect();
+    solved.sort_by(f64::total_cmp);
+
+    let n = runtimes.len() as f64;
+    let mut budgets = vec![0.0];
+    let mut fractions = vec![0.0];
+    for (k, t) in solved.into_iter().enumerate() {
+        budgets.push(t);
+        fractions.push((k + 1) as f64 / n);
+    }
+    (budgets, fractions)
+}
+
+fn generate_performance_profile(
+    func_name: &str,
+    dim: usize,
+    per_run_runtimes: &[f64],
+    per_run_success: &[bool],
+    format: PlotFormat,
+) {
+    let (mut budgets, mut fractions) = success_ecdf(per_run_runtimes, per_run_success);
+    // Extend the last step to the slowest finished run so the final level is visible
+    let slowest = per_run_runtimes.iter().copied().filter(|t| t.is_finite()).fold(0.0, f64::max);
+    if slowest > *budgets.last().unwrap() {
+        budgets.push(slowest);
+        fractions.push(*fractions.last().unwrap());
     }
 
-    let current_sz: Vec<f64> = current.iter().map(|s| s.avg_solution_set_size).collect();
-    let current_std_sz: Vec<f64> = current.iter().map(|s| s.std_solution_set_size).collect();
+    let mut plot = Plot::new();
+    plot.set_layout(
+        Layout::new()
+            .title(Title::with_text(format!("{} Performance Profile (Dim {})", func_name, dim)))
+            .x_axis(Axis::new().title(Title::with_text("Time Budget (s)")))
+            .y_axis(
+                Axis::new()
+                    .title(Title::with_text("Fraction of Runs Solved"))
+                    .range(vec![0.0, 1.0]),
+            ),
+    );
     plot.add_trace(
-        Scatter::new(x_vals.clone(), current_sz)
-            .name("Current SolSize")
-            .mode(Mode::LinesMarkers)
-            .error_y(ErrorData::new(ErrorType::Data).array(current_std_sz))
-            .x_axis("x3")
-            .y_axis("y3"),
+        Scatter::new(budgets, fractions)
+            .name(format!("Dim {}", dim))
+            .mode(Mode::Lines)
+            .line(Line::new().shape(LineShape::Hv)),
     );
 
-    if let Some(base) = baseline {
-        let base_sz: Vec<f64> = base.iter().map(|s| s.avg_solution_set_size).collect();
-        let base_std_sz: Vec<f64> = base.iter().map(|s| s.std_solution_set_size).collect();
-        plot.add_trace(
-            Scatter::new(x_vals.clone(), base_sz)
-                .name("Baseline SolSize")
-                .mode(Mode::LinesMarkers)
-                .error_y(ErrorData::new(ErrorType::Data).array(base_std_sz))
-                .x_axis("x3")
-                .y_axis("y3"),
+    write_plot(&plot, &format!("{}_profile_d{}", func_name.to_lowercase(), dim), format);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Stat point with the given headline numbers and every other metric zero or unset.
+    fn stat_point(dim: usize, success_rate: f64, avg_runtime_sec: f64) -> StatPoint {
+        serde_json::from_value(json!({
+            "dim": dim,
+            "success_rate": success_rate,
+            "avg_runtime_sec": avg_runtime_sec,
+            "std_runtime_sec": 0.0,
+            "avg_stage1_sec": 0.0,
+            "avg_stage2_sec": 0.0,
+            "avg_solution_set_size": 1.0,
+            "std_solution_set_size": 0.0,
+            "avg_best_obj": 0.0,
+        }))
+        .unwrap()
+    }
+
+    /// One-second run with a single solution and no optional measurements.
+    fn run_record(seed: u64, success: bool) -> RunRecord {
+        RunRecord {
+            seed,
+            runtime_sec: 1.0,
+            cpu_sec: None,
+            peak_memory_bytes: None,
+            stage1_sec: 0.25,
+            stage2_sec: 0.5,
+            solution_set_size: 1,
+            near_optimal: usize::from(success),
+            min_pairwise_distance: None,
+            best_obj: if success { 0.0 } else { 1.0 },
+            evaluations: 100,
+            success,
+            distance: None,
+            convergence: Vec::new(),
+            budget_exhausted: false,
+            best_point: Vec::new(),
+        }
+    }
+
+    /// Runs of two functions with two unsuccessful runs among them, plus a failed
+    /// run and a timeout that only count towards the trials.
+    fn sample_runs() -> BTreeMap<String, Vec<DimRuns>> {
+        let detailed = RunRecord {
+            runtime_sec: 2.5,
+            cpu_sec: Some(2.0),
+            peak_memory_bytes: Some(1 << 20),
+            min_pairwise_distance: Some(0.5),
+            distance: Some(1.5),
+            convergence: vec![4.0, 2.0, 1.0],
+            budget_exhausted: true,
+            best_point: vec![0.5, -1.0],
+            ..run_record(SEED_STEP, false)
+        };
+        BTreeMap::from([
+            (
+                "Ackley".to_string(),
+                vec![DimRuns { dim: 2, runs: vec![run_record(0, true)], failed: 0, timeouts: 0 }],
+            ),
+            (
+                "Sphere".to_string(),
+                vec![
+                    DimRuns {
+                        dim: 2,
+                        runs: vec![run_record(0, true), detailed],
+                        failed: 1,
+                        timeouts: 0,
+                    },
+                    DimRuns { dim: 10, runs: vec![run_record(0, false)], failed: 0, timeouts: 1 },
+                ],
+            ),
+        ])
+    }
+
+    fn all_stats(data: Vec<(&str, Vec<StatPoint>)>) -> AllStats {
+        AllStats {
+            schema_version: SCHEMA_VERSION,
+            data: data.into_iter().map(|(name, points)| (name.to_string(), points)).collect(),
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn parse_dims_expands_ranges_and_steps() {
+        assert_eq!(parse_dims("2-5").unwrap().0, vec![2, 3, 4, 5]);
+        assert_eq!(parse_dims("10-50:20, 100").unwrap().0, vec![10, 30, 50, 100]);
+    }
+
+    #[test]
+    fn parse_dims_sorts_and_dedups_overlaps() {
+        assert_eq!(parse_dims("50,10,2-4,3-6").unwrap().0, vec![2, 3, 4, 5, 6, 10, 50]);
+    }
+
+    #[test]
+    fn parse_dims_rejects_invalid_tokens() {
+        for input in ["", "abc", "5-2", "2-10:0", "0,5", "2-x", "1-3:y"] {
+            assert!(parse_dims(input).is_err(), "accepted '{}'", input);
+        }
+    }
+
+    #[test]
+    fn checkpoint_resumes_only_with_the_same_settings() {
+        let cli = Cli::parse_from(["globalsearch-benches"]);
+        let settings = |seeds: &[u64]| CheckpointSettings {
+            dims: vec![2, 10],
+            metadata: RunMetadata::new(&cli, &OQNLPParams::default(), seeds),
+        };
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let checkpoint = Checkpoint::open(path, settings(&[0, SEED_STEP])).unwrap();
+        checkpoint.record("Sphere", &DimRuns { dim: 2, runs: Vec::new(), failed: 1, timeouts: 0 });
+
+        let resumed = Checkpoint::open(path, settings(&[0, SEED_STEP])).unwrap();
+        assert_eq!(resumed.completed_cells(), 1);
+        assert_eq!(resumed.restore("Sphere", 2).map(|d| d.failed), Some(1));
+        assert!(resumed.restore("Sphere", 10).is_none());
+
+        let Err(err) = Checkpoint::open(path, settings(&[0, 1])) else {
+            panic!("resumed with different seeds");
+        };
+        assert!(err.contains("seeds"), "{}", err);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn stats_to_csv_writes_one_row_per_stat_point() {
+        let stats = all_stats(vec![
+            ("Ackley", vec![stat_point(2, 0.5, 1.25), stat_point(10, 0.25, 3.0)]),
+            ("Sphere", vec![stat_point(10, 1.0, 0.5)]),
+        ]);
+        let csv = stats_to_csv(&stats);
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), stats.data.values().map(Vec::len).sum::<usize>());
+
+        let column = |name| header.iter().position(|&h| h == name).unwrap();
+        for (row, (name, s)) in rows
+            .iter()
+            .zip(stats.data.iter().flat_map(|(name, points)| points.iter().map(move |s| (name, s))))
+        {
+            assert_eq!(row.len(), header.len());
+            assert_eq!(row[column("function")], name);
+            assert_eq!(row[column("dim")].parse::<usize>().unwrap(), s.dim);
+            assert_eq!(row[column("success_rate")].parse::<f64>().unwrap(), s.success_rate);
+            assert_eq!(row[column("avg_runtime_sec")].parse::<f64>().unwrap(), s.avg_runtime_sec);
+            assert_eq!(row[column("avg_cpu_sec")], "");
+        }
+    }
+
+    #[test]
+    fn stats_to_markdown_formats_a_table_per_function() {
+        let stats =
+            all_stats(vec![("Sphere", vec![stat_point(2, 1.0, 0.5), stat_point(10, 0.25, 12.0)])]);
+        let expected = "### Sphere\n\n\
+            | Dim | Success Rate | Avg Runtime (s) | Avg SolSize | Avg Best Obj |\n\
+            |---:|---:|---:|---:|---:|\n\
+            | 2 | 1.00 | 0.5000 | 1.0 | 0.000000e0 |\n\
+            | 10 | 0.25 | 12.0000 | 1.0 | 0.000000e0 |\n\n";
+        assert_eq!(stats_to_markdown(&stats, None), expected);
+    }
+
+    #[test]
+    fn stats_to_markdown_adds_deltas_against_a_baseline() {
+        let current =
+            all_stats(vec![("Sphere", vec![stat_point(2, 1.0, 0.5), stat_point(10, 0.25, 12.0)])]);
+        let baseline = all_stats(vec![("Sphere", vec![stat_point(2, 0.75, 1.0)])]);
+        let md = stats_to_markdown(&current, Some(&baseline));
+        let rows: Vec<&str> = md.lines().skip(4).take(2).collect();
+        assert_eq!(rows[0], "| 2 | 1.00 | 0.5000 | 1.0 | 0.000000e0 | +0.25 | -0.5000 | - |");
+        assert_eq!(rows[1], "| 10 | 0.25 | 12.0000 | 1.0 | 0.000000e0 | - | - | - |");
+    }
+
+    #[test]
+    fn rastrigin_distance_is_measured_to_the_origin() {
+        let opts = Cli::parse_from(["globalsearch-benches"]).run_options();
+        let best = Array1::from_vec(vec![3.0, 4.0]);
+        let optimum = opts.nearest_argmin(&Rastrigin, 2, &best).unwrap();
+        assert_eq!(optimum, Array1::<f64>::zeros(2));
+        assert_eq!(euclidean_distance(&best, &optimum), 5.0);
+
+        // A shift moves the optimum along with the point
+        let opts = Cli::parse_from(["globalsearch-benches", "--shift", "1"]).run_options();
+        let best = Array1::from_vec(vec![4.0, 5.0]);
+        let optimum = opts.nearest_argmin(&Rastrigin, 2, &best).unwrap();
+        assert_eq!(euclidean_distance(&best, &optimum), 5.0);
+    }
+
+    #[test]
+    fn run_sweep_completes_with_a_tiny_iteration_budget() {
+        let mut cli = Cli::parse_from(["globalsearch-benches", "--population-size", "20"]);
+        let sweep = Sweep { param: SweepParam::Iterations, values: vec![1, 2] };
+        let functions: Vec<Arc<dyn BenchmarkFn>> = vec![Arc::new(Sphere)];
+        let curves = run_sweep(&sweep, &functions, &[2], &run_seeds(2), &[], &mut cli);
+
+        let passes = &curves["Sphere"];
+        assert_eq!(passes.iter().map(|&(value, _)| value).collect::<Vec<_>>(), vec![1, 2]);
+        for (_, stats) in passes {
+            assert_eq!(stats.len(), 1);
+            assert_eq!(stats[0].runs_used, 2);
+        }
+    }
+
+    #[test]
+    fn failed_runs_abort_only_under_fail_fast() {
+        let keep_going = Cli::parse_from(["globalsearch-benches", "--keep-going"]);
+        assert!(!keep_going.aborts_on(&BenchError::NoSolution));
+        assert!(!keep_going.aborts_on(&BenchError::NonFiniteObjective(f64::NAN)));
+        assert!(keep_going.aborts_on(&BenchError::OptimumOutOfBounds));
+
+        let fail_fast = Cli::parse_from(["globalsearch-benches", "--fail-fast"]);
+        assert!(fail_fast.aborts_on(&BenchError::NoSolution));
+        assert!(fail_fast.aborts_on(&BenchError::OptimumOutOfBounds));
+    }
+
+    #[test]
+    fn parse_seeds_skips_whitespace_and_blank_lines() {
+        assert_eq!(parse_seeds(" 7, 42\n\n  3 \r\n,\n100").unwrap(), vec![7, 42, 3, 100]);
+        assert!(parse_seeds("\n , \n").is_err());
+
+        let err = parse_seeds("1\n2x\n3").unwrap_err();
+        assert!(err.contains("'2x'"), "{}", err);
+    }
+
+    #[test]
+    fn stage_fractions_stay_within_the_runtime() {
+        let dim_runs = DimRuns {
+            dim: 2,
+            runs: vec![
+                RunRecord {
+                    runtime_sec: 2.0,
+                    stage1_sec: 0.5,
+                    stage2_sec: 1.25,
+                    ..run_record(0, true)
+                },
+                RunRecord {
+                    runtime_sec: 4.0,
+                    stage1_sec: 1.0,
+                    stage2_sec: 2.75,
+                    ..run_record(1, true)
+                },
+            ],
+            failed: 0,
+            timeouts: 0,
+        };
+        let s = aggregate(&dim_runs, 0.0).unwrap();
+        assert_eq!(s.stage1_fraction, 0.25);
+        assert_eq!(s.stage2_fraction, 2.0 / 3.0);
+        assert!(s.stage1_fraction + s.stage2_fraction <= 1.0);
+
+        assert_eq!(time_fraction(0.0, 0.0), 0.0);
+        assert_eq!(time_fraction(1.0, 0.0), 0.0);
+    }
+
+    /// Function whose runs never finish on their own, only once they are cancelled.
+    struct Stalled;
+
+    impl BenchmarkFn for Stalled {
+        fn name(&self) -> &str {
+            "Stalled"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &[]
+        }
+
+        fn run(
+            &self,
+            _dim: usize,
+            _seed: u64,
+            _params: &OQNLPParams,
+            opts: &RunOptions,
+        ) -> Result<RunResult, BenchError> {
+            let cancel = opts.cancel.as_ref().expect("run without a timeout");
+            while !cancel.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Err(BenchError::NoSolution)
+        }
+    }
+
+    #[test]
+    fn run_with_timeout_gives_up_on_a_stalled_run() {
+        let func: Arc<dyn BenchmarkFn> = Arc::new(Stalled);
+        let cli = Cli::parse_from(["globalsearch-benches", "--runs", "2", "--timeout-sec", "0.01"]);
+        let outcome =
+            run_with_timeout(&func, 2, 0, &cli.oqnlp_params(), &cli.run_options(), cli.timeout_sec);
+        assert!(outcome.is_none());
+
+        let mut log = ProgressLog::buffered(None);
+        let results = benchmark_function(&func, &[2], &run_seeds(2), &[], &cli, None, &mut log);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].runs.is_empty());
+        assert_eq!((results[0].failed, results[0].timeouts), (0, 2));
+    }
+
+    #[test]
+    fn success_ecdf_steps_through_successful_runtimes() {
+        // Failed and timed-out runs only count towards the total
+        let runtimes = [3.0, 1.0, f64::INFINITY, 2.0, 0.5];
+        let success = [true, true, false, true, false];
+        let (budgets, fractions) = success_ecdf(&runtimes, &success);
+        assert_eq!(budgets, vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(fractions, vec![0.0, 0.2, 0.4, 0.6]);
+
+        let (budgets, fractions) = success_ecdf(&[f64::INFINITY, 1.0], &[false, false]);
+        assert_eq!((budgets, fractions), (vec![0.0], vec![0.0]));
+    }
+
+    #[test]
+    fn success_ci_half_width_shrinks_with_more_trials() {
+        assert_eq!(success_ci_half_width(0, 0), f64::INFINITY);
+        assert!(success_ci_half_width(50, 100) < success_ci_half_width(5, 10));
+        // A rate near 0 or 1 is pinned down with fewer trials than one near 0.5
+        assert!(success_ci_half_width(20, 20) < success_ci_half_width(10, 20));
+    }
+
+    #[test]
+    fn ci_target_stops_a_bernoulli_stream_once_met() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let cli = Cli::parse_from(["globalsearch-benches", "--runs", "20", "--ci-target", "0.1"]);
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut successes = 0;
+        let mut trials = 0;
+        while !cli.ci_target_met(successes, trials) {
+            assert!(trials < cli.max_runs, "no stop after {} trials", trials);
+            successes += usize::from(rng.random_bool(0.3));
+            trials += 1;
+        }
+        assert!(trials > cli.runs);
+        assert!(success_ci_half_width(successes, trials) < 0.1);
+
+        // An always-successful stream stops at the minimum number of runs
+        assert!(cli.ci_target_met(20, 20));
+        assert!(!cli.ci_target_met(19, 19));
+        assert!(!Cli::parse_from(["globalsearch-benches"]).ci_target_met(20, 20));
+    }
+
+    #[test]
+    fn merge_stats_takes_the_union_of_functions() {
+        let mut stored = all_stats(vec![
+            ("Ackley", vec![stat_point(2, 0.5, 1.0)]),
+            ("Sphere", vec![stat_point(2, 0.5, 1.0)]),
+        ]);
+        let current = all_stats(vec![
+            ("Rastrigin", vec![stat_point(2, 0.75, 2.0)]),
+            ("Sphere", vec![stat_point(2, 1.0, 0.5)]),
+        ]);
+        merge_stats(&mut stored, &current, OnConflict::Replace).unwrap();
+        assert_eq!(stored.data.keys().collect::<Vec<_>>(), ["Ackley", "Rastrigin", "Sphere"]);
+        assert_eq!(stored.data["Ackley"][0].success_rate, 0.5);
+        assert_eq!(stored.data["Sphere"][0].success_rate, 1.0);
+
+        let Err(err) = merge_stats(&mut stored, &current, OnConflict::Error) else {
+            panic!("merged conflicting functions");
+        };
+        assert_eq!(err, "results already stored for Rastrigin, Sphere");
+    }
+
+    #[test]
+    fn merge_stats_refuses_another_schema_version() {
+        let mut stored = all_stats(vec![("Ackley", vec![stat_point(2, 0.5, 1.0)])]);
+        stored.schema_version = SCHEMA_VERSION - 1;
+        let current = all_stats(vec![("Sphere", vec![stat_point(2, 1.0, 0.5)])]);
+
+        let Err(err) = merge_stats(&mut stored, &current, OnConflict::Replace) else {
+            panic!("merged across schema versions");
+        };
+        assert!(err.contains("--migrate-baseline"), "{}", err);
+        assert_eq!(stored.data.keys().collect::<Vec<_>>(), ["Ackley"]);
+    }
+
+    #[test]
+    fn raw_dump_round_trips_through_a_file() {
+        let runs = sample_runs();
+        let path = std::env::temp_dir().join(format!("raw-dump-{}.json", std::process::id()));
+        std::fs::write(&path, serde_json::to_string_pretty(&raw_dump(&runs)).unwrap()).unwrap();
+        let loaded = load_raw_dump(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(raw_dump(&loaded)).unwrap(),
+            serde_json::to_value(raw_dump(&runs)).unwrap()
         );
+        assert_eq!(loaded["Sphere"][1].dim, 10);
+        assert_eq!(loaded["Sphere"][1].timeouts, 1);
+    }
+
+    #[test]
+    fn load_raw_dump_reads_the_legacy_run_lists() {
+        let run = serde_json::to_value(RawRun::from(&run_record(7, true))).unwrap();
+        let path = std::env::temp_dir().join(format!("raw-legacy-{}.json", std::process::id()));
+        std::fs::write(&path, json!({ "Sphere": { "2": [run.clone(), run] } }).to_string())
+            .unwrap();
+        let loaded = load_raw_dump(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let dim_runs = &loaded["Sphere"][0];
+        assert_eq!(dim_runs.dim, 2);
+        assert_eq!(dim_runs.runs.len(), 2);
+        assert_eq!(dim_runs.runs[0].seed, 7);
+        assert_eq!((dim_runs.failed, dim_runs.timeouts), (0, 0));
+    }
+
+    #[test]
+    fn migrated_v1_file_loads_with_the_derived_metrics() {
+        let dir = std::env::temp_dir();
+        let input = dir.join(format!("stats-v1-{}.json", std::process::id()));
+        let output = dir.join(format!("stats-migrated-{}.json", std::process::id()));
+        let v1 = json!({
+            "data": {
+                "Sphere": [{
+                    "dim": 2,
+                    "success_rate": 0.5,
+                    "avg_runtime_sec": 2.0,
+                    "std_runtime_sec": 0.5,
+                    "avg_stage1_sec": 0.5,
+                    "avg_stage2_sec": 1.5,
+                    "avg_solution_set_size": 1.0,
+                    "std_solution_set_size": 0.0,
+                    "avg_best_obj": 0.0,
+                    "raw_runtimes": [1.5, 2.0, 2.0, 2.5],
+                }],
+            },
+        });
+        std::fs::write(&input, v1.to_string()).unwrap();
+        let (input, output) = (input.to_str().unwrap(), output.to_str().unwrap());
+
+        let loaded = load_stats(input).unwrap();
+        assert_eq!(loaded.schema_version, 1);
+        assert_eq!(loaded.data["Sphere"][0].stage1_fraction, 0.0);
+
+        migrate_baseline(input, output, 0.25);
+        let migrated = load_stats(output).unwrap();
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(output).unwrap();
+
+        assert_eq!(migrated.schema_version, SCHEMA_VERSION);
+        let s = &migrated.data["Sphere"][0];
+        assert_eq!((s.stage1_fraction, s.stage2_fraction), (0.25, 0.75));
+        assert_eq!(s.runs_used, 4);
+        assert_eq!(s.trimmed_mean_runtime_sec, 2.0);
+        assert_eq!(s.ci95_runtime_sec, 1.96 * 0.5 / 2.0);
+        assert_eq!((s.success_rate_ci_low, s.success_rate_ci_high), wilson_interval(2, 4));
+    }
+
+    #[test]
+    fn load_stats_reports_unreadable_files() {
+        let path = std::env::temp_dir().join(format!("stats-bad-{}.json", std::process::id()));
+        assert!(load_stats(path.to_str().unwrap()).is_err());
+        std::fs::write(&path, "{\"data\": []}").unwrap();
+        let result = load_stats(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let Err(err) = result else {
+            panic!("loaded results without a map of functions");
+        };
+        assert!(err.contains("expected a map"), "{}", err);
+    }
+
+    #[test]
+    fn fit_power_law_recovers_the_exponent() {
+        let dims = [2.0_f64, 5.0, 10.0, 50.0, 100.0];
+        let times = dims.map(|d| 0.01 * d.powf(1.5));
+        let (a, b, r2) = fit_power_law(&dims, &times).unwrap();
+        assert!((a - 0.01).abs() < 1e-9, "a = {}", a);
+        assert!((b - 1.5).abs() < 1e-9, "b = {}", b);
+        assert!((r2 - 1.0).abs() < 1e-9, "r2 = {}", r2);
+    }
+
+    #[test]
+    fn fit_power_law_needs_two_distinct_dims() {
+        assert!(fit_power_law(&[], &[]).is_none());
+        assert!(fit_power_law(&[10.0], &[1.0]).is_none());
+        assert!(fit_power_law(&[10.0, 10.0], &[1.0, 2.0]).is_none());
+        // Points without a positive runtime don't count
+        assert!(fit_power_law(&[2.0, 10.0], &[1.0, 0.0]).is_none());
     }
 
-    let filename = format!("plots/{}_benchmark.html", func_name.to_lowercase());
-    plot.write_html(filename);
+    #[test]
+    fn seed_mixes_dim_gives_each_dimension_its_own_seeds() {
+        let dims = [2, 5, 10, 50];
+        let plain = Cli::parse_from(["globalsearch-benches"]);
+        for seed in run_seeds(3) {
+            assert!(dims.iter().all(|&dim| plain.run_seed(seed, dim) == seed));
+        }
+
+        let mixed = Cli::parse_from(["globalsearch-benches", "--seed-mixes-dim"]);
+        let seeds: BTreeSet<u64> = run_seeds(3)
+            .into_iter()
+            .flat_map(|seed| dims.map(|dim| mixed.run_seed(seed, dim)))
+            .collect();
+        assert_eq!(seeds.len(), 3 * dims.len());
+        assert_eq!(mixed.run_seed(SEED_STEP, 10), mix_seed(SEED_STEP, 10));
+    }
+
+    /// Function whose first run finds no solution and whose later runs succeed,
+    /// recording the seed of every run.
+    struct Flaky {
+        seeds: Mutex<Vec<u64>>,
+    }
+
+    impl BenchmarkFn for Flaky {
+        fn name(&self) -> &str {
+            "Flaky"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &[]
+        }
+
+        fn run(
+            &self,
+            dim: usize,
+            seed: u64,
+            _params: &OQNLPParams,
+            _opts: &RunOptions,
+        ) -> Result<RunResult, BenchError> {
+            let mut seeds = self.seeds.lock().unwrap();
+            seeds.push(seed);
+            if seeds.len() == 1 {
+                return Err(BenchError::NoSolution);
+            }
+            Ok(RunResult {
+                runtime: Duration::from_millis(10),
+                cpu_time: None,
+                peak_memory_bytes: None,
+                stage1_runtime: Duration::from_millis(2),
+                stage2_runtime: Duration::from_millis(8),
+                best_obj: 0.0,
+                best_point: Array1::zeros(dim),
+                solution_set_size: 1,
+                solution_objectives: vec![0.0],
+                min_pairwise_distance: None,
+                function_evaluations: 100,
+                convergence: Vec::new(),
+                budget_exhausted: false,
+            })
+        }
+    }
+
+    #[test]
+    fn degenerate_run_is_retried_with_a_mixed_seed() {
+        let flaky = Arc::new(Flaky { seeds: Mutex::new(Vec::new()) });
+        let func: Arc<dyn BenchmarkFn> = flaky.clone();
+        let cli = Cli::parse_from(["globalsearch-benches", "--runs", "1", "--retry", "1"]);
+        let base = SEED_STEP;
+        let mut log = ProgressLog::buffered(None);
+        let results = benchmark_function(&func, &[2], &[base], &[], &cli, None, &mut log);
+
+        assert_eq!(*flaky.seeds.lock().unwrap(), vec![base, mix_seed(base, 1)]);
+        let dim_runs = &results[0];
+        assert_eq!((dim_runs.failed, dim_runs.timeouts), (0, 0));
+        assert_eq!(dim_runs.runs.len(), 1);
+        assert_eq!(dim_runs.runs[0].seed, mix_seed(base, 1));
+        assert!(dim_runs.runs[0].success);
+    }
+
+    /// Parses `args` and applies the config file holding `toml`, as `parse_with_config` does.
+    fn cli_with_config(args: &[&str], toml: &str) -> Result<Cli, String> {
+        use std::sync::atomic::AtomicUsize;
+
+        // Tests run in parallel, so every call gets its own file
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        let call = CALLS.fetch_add(1, Ordering::Relaxed);
+        let path =
+            std::env::temp_dir().join(format!("config-{}-{}.toml", std::process::id(), call));
+        std::fs::write(&path, toml).unwrap();
+        let config = config::load(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let matches = Cli::command().get_matches_from(args);
+        let mut cli = Cli::from_arg_matches(&matches).unwrap();
+        cli.apply_config(config?, &matches)?;
+        Ok(cli)
+    }
+
+    #[test]
+    fn command_line_overrides_config_overrides_defaults() {
+        let toml = "dims = [10, 2, 10]\n\
+            runs = 5\n\
+            tolerance = 0.001\n\
+            [params]\n\
+            population_size = 50\n\
+            iterations = 10\n";
+        let cli =
+            cli_with_config(&["globalsearch-benches", "--runs", "7", "--iterations", "20"], toml)
+                .unwrap();
+        assert_eq!(cli.runs, 7);
+        assert_eq!(cli.iterations, Some(20));
+        assert_eq!(cli.dims.unwrap().0, vec![2, 10]);
+        assert_eq!(cli.tolerance, Some(0.001));
+        assert_eq!(cli.population_size, Some(50));
+        assert_eq!(cli.wait_cycle, None);
+
+        let cli = cli_with_config(&["globalsearch-benches"], "").unwrap();
+        assert_eq!(cli.runs, 20);
+        assert!(cli.dims.is_none() && cli.tolerance.is_none());
+    }
+
+    #[test]
+    fn config_values_are_checked_like_the_flags() {
+        let args = ["globalsearch-benches"];
+        for toml in ["dims = [0, 2]", "dims = []", "runs = 0", "tolerance = -1.0"] {
+            assert!(cli_with_config(&args, toml).is_err(), "accepted '{}'", toml);
+        }
+        // Invalid config values are never read when the flag is given
+        assert!(cli_with_config(&["globalsearch-benches", "--runs", "3"], "runs = 0").is_ok());
+    }
+
+    #[test]
+    fn saved_stats_reserialize_byte_identically() {
+        let cli = Cli::parse_from(["globalsearch-benches"]);
+        let stats = AllStats {
+            schema_version: SCHEMA_VERSION,
+            data: sample_runs()
+                .iter()
+                .map(|(name, per_dim)| {
+                    (name.clone(), per_dim.iter().filter_map(|d| aggregate(d, 0.1)).collect())
+                })
+                .collect(),
+            metadata: Some(RunMetadata::new(&cli, &cli.oqnlp_params(), &run_seeds(3))),
+        };
+        let saved = serde_json::to_string_pretty(&stats).unwrap();
+        let reloaded: AllStats = serde_json::from_str(&saved).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&reloaded).unwrap(), saved);
+    }
+
+    #[test]
+    fn select_functions_keeps_the_usual_order_case_insensitively() {
+        let names = [" SPHERE ", "rosenbrock", "Ackley", "sphere"].map(String::from);
+        let selected = select_functions(all_functions(XinSheYangVariant::default()), &names)
+            .unwrap_or_else(|e| panic!("{}", e));
+        let selected: Vec<&str> = selected.iter().map(|f| f.name()).collect();
+        assert_eq!(selected, ["Rosenbrock", "Ackley", "Sphere"]);
+    }
+
+    #[test]
+    fn select_functions_suggests_the_closest_name() {
+        let select = |name: &str| {
+            let Err(err) =
+                select_functions(all_functions(XinSheYangVariant::default()), &[name.to_string()])
+            else {
+                panic!("selected unknown function '{}'", name);
+            };
+            err
+        };
+        assert_eq!(select("Rastrign"), "unknown function 'rastrign' (did you mean 'Rastrigin'?)");
+        assert_eq!(select("sphre"), "unknown function 'sphre' (did you mean 'Sphere'?)");
+        assert_eq!(select("foobarbaz"), "unknown function 'foobarbaz' (see --list-functions)");
+    }
+
+    #[test]
+    fn edit_distance_counts_character_edits() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+        assert_eq!(edit_distance("flaw", "lawn"), 2);
+        // Characters, not bytes
+        assert_eq!(edit_distance("é", "e"), 1);
+    }
+
+    #[test]
+    fn tag_filter_keeps_only_tagged_functions() {
+        let all = || all_functions(XinSheYangVariant::default());
+        let names = |functions: Vec<Arc<dyn BenchmarkFn>>| -> Vec<String> {
+            functions.iter().map(|f| f.name().to_string()).collect()
+        };
+        let separable = names(with_tag(all(), "separable"));
+        let non_separable = names(with_tag(all(), "non-separable"));
+        assert!(separable.contains(&"Sphere".to_string()));
+        assert!(non_separable.contains(&"Rosenbrock".to_string()));
+        // "separable" must not match "non-separable" as a substring
+        assert!(separable.iter().all(|name| !non_separable.contains(name)));
+
+        let low_dim = with_tag(all(), "low-dim-only");
+        assert!(!low_dim.is_empty());
+        assert!(low_dim.iter().all(|f| f.tags().contains(&"low-dim-only")));
+
+        // Combined with a selection by name
+        let selected = select_functions(all(), &["Sphere".into(), "Rosenbrock".into()]).unwrap();
+        assert_eq!(names(with_tag(selected, "separable")), ["Sphere"]);
+
+        assert!(Cli::try_parse_from(["globalsearch-benches", "--tag", "convex"]).is_err());
+    }
+
+    #[test]
+    fn stats_from_a_raw_dump_match_the_live_runs() {
+        let live = sample_runs();
+        let dump: RawDump =
+            serde_json::from_str(&serde_json::to_string(&raw_dump(&live)).unwrap()).unwrap();
+        for (name, per_dim) in &live {
+            for dim_runs in per_dim {
+                let restored = dump[name][&dim_runs.dim].clone().into_dim_runs(dim_runs.dim);
+                assert_eq!(
+                    serde_json::to_value(aggregate(&restored, 0.1)).unwrap(),
+                    serde_json::to_value(aggregate(dim_runs, 0.1)).unwrap(),
+                    "{} at dim {}",
+                    name,
+                    dim_runs.dim
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn results_table_has_a_row_per_run_including_failures() {
+        // Ackley isn't among the functions, so its optimum and gap are left empty
+        let functions: Vec<Arc<dyn BenchmarkFn>> = vec![Arc::new(Sphere)];
+        let expected = "problem,dimension,f_best,f_opt,gap,time,evals,status\n\
+            Ackley,2,0,,,1,100,optimal\n\
+            Sphere,2,0,0,0,1,100,optimal\n\
+            Sphere,2,1,0,1,2.5,100,suboptimal\n\
+            Sphere,2,,,,,,failed\n\
+            Sphere,10,1,0,1,1,100,suboptimal\n\
+            Sphere,10,,,,,,failed\n";
+        assert_eq!(results_table(&sample_runs(), &functions), expected);
+    }
+
+    #[test]
+    fn parse_sweep_reads_the_parameter_and_its_values() {
+        let sweep = parse_sweep("population_size=10, 20,40,").unwrap();
+        assert!(matches!(sweep.param, SweepParam::PopulationSize));
+        assert_eq!(sweep.values, vec![10, 20, 40]);
+
+        let sweep = parse_sweep(" iterations =5").unwrap();
+        assert!(matches!(sweep.param, SweepParam::Iterations));
+        assert_eq!(sweep.values, vec![5]);
+
+        let params = sweep.apply(&OQNLPParams::default(), 5);
+        assert_eq!(params.iterations, 5);
+        assert_eq!(params.population_size, OQNLPParams::default().population_size);
+    }
+
+    #[test]
+    fn parse_sweep_rejects_invalid_input() {
+        for input in
+            ["", "iterations", "wait_cycle=1,2", "iterations=", "iterations=1,x", "iterations=0,5"]
+        {
+            assert!(parse_sweep(input).is_err(), "accepted '{}'", input);
+        }
+    }
+
+    #[test]
+    fn tiny_iteration_budget_lowers_the_success_rate() {
+        let func: Arc<dyn BenchmarkFn> = Arc::new(Rastrigin);
+        let runs = |budget: &[&str]| {
+            let args = ["globalsearch-benches", "--population-size", "200", "--iterations", "50"];
+            let cli = Cli::parse_from(args.iter().chain(budget));
+            let mut log = ProgressLog::buffered(None);
+            benchmark_function(&func, &[2], &run_seeds(10), &[], &cli, None, &mut log)
+                .remove(0)
+                .runs
+        };
+        let successes = |runs: &[RunRecord]| runs.iter().filter(|r| r.success).count();
+
+        let full = runs(&[]);
+        assert!(successes(&runs(&["--budget-iters", "1"])) < successes(&full));
+
+        // A budget at or above the configured iterations changes nothing
+        let unlimited = runs(&["--budget-iters", "50"]);
+        assert_eq!(successes(&unlimited), successes(&full));
+        assert!(unlimited.iter().all(|r| !r.budget_exhausted));
+    }
+
+    #[test]
+    fn failed_runs_reruns_only_the_unsuccessful_completed_runs() {
+        // The failed run and the timeout have no seed to rerun
+        let failures = failed_runs(&sample_runs());
+        let scheduled: Vec<(&str, usize, u64)> =
+            failures.iter().map(|f| (f.func_name.as_str(), f.dim, f.seed)).collect();
+        assert_eq!(scheduled, [("Sphere", 2, SEED_STEP), ("Sphere", 10, 0)]);
+    }
+
+    #[test]
+    fn quadratic_reference_is_anchored_at_the_smallest_dim() {
+        // Unsorted on purpose; the anchor is dim 5, not the first point
+        let stats = [stat_point(20, 1.0, 9.0), stat_point(5, 1.0, 0.5), stat_point(10, 1.0, 3.0)];
+        let (dims, times) = reference_runtimes(&stats, 2);
+        assert_eq!(dims, vec![5, 10, 20]);
+        assert_eq!(times, vec![0.5, 2.0, 8.0]);
+
+        assert_eq!(reference_runtimes(&[], 2), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn normalized_runtimes_divide_by_the_reference_at_each_dim() {
+        let stats = all_stats(vec![
+            (
+                "Ackley",
+                vec![
+                    stat_point(2, 1.0, 1.0),
+                    stat_point(5, 1.0, 3.0),
+                    stat_point(10, 1.0, 5.0),
+                    stat_point(20, 1.0, 8.0),
+                ],
+            ),
+            (
+                "Sphere",
+                vec![stat_point(2, 1.0, 2.0), stat_point(10, 1.0, 0.0), stat_point(20, 1.0, 4.0)],
+            ),
+        ]);
+        // Matched case-insensitively; dim 5 wasn't run by Sphere and its dim 10 runtime is zero
+        let ratios = normalized_runtimes(&stats, "sphere").unwrap();
+        assert_eq!(ratios["Ackley"], vec![(2, 0.5), (20, 2.0)]);
+        assert_eq!(ratios["Sphere"], vec![(2, 1.0), (20, 1.0)]);
+
+        assert_eq!(normalized_runtimes(&stats, "rastrigin"), Err("rastrigin was not run".into()));
+    }
 }
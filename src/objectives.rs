@@ -0,0 +1,10 @@
+//! Closed-form objectives shared by the benchmark functions and the Stage 1 visualizer.
+
+/// Six-hump camel function of `x[0]` and `x[1]`.
+pub fn six_hump_camel(x: &[f64]) -> f64 {
+    let x1 = x[0];
+    let x2 = x[1];
+    (4.0 - 2.1 * x1.powi(2) + x1.powi(4) / 3.0) * x1.powi(2)
+        + x1 * x2
+        + (-4.0 + 4.0 * x2.powi(2)) * x2.powi(2)
+}
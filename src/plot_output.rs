@@ -0,0 +1,57 @@
+use clap::ValueEnum;
+use plotly::Plot;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PlotFormat {
+    Html,
+    Png,
+    Svg,
+    All,
+}
+
+/// Writes `plot` to `plots/<stem>.<ext>` in the requested format(s). Static
+/// export falls back to HTML when kaleido is unavailable.
+pub fn write_plot(plot: &Plot, stem: &str, format: PlotFormat) {
+    let _ = std::fs::create_dir_all("plots");
+    let html = format!("plots/{}.html", stem);
+
+    if matches!(format, PlotFormat::Html | PlotFormat::All) {
+        plot.write_html(&html);
+    }
+    if format == PlotFormat::Html {
+        return;
+    }
+
+    if let Err(e) = export_images(plot, stem, format) {
+        eprintln!("Warning: static export of {} failed ({}), writing {} instead", stem, e, html);
+        plot.write_html(&html);
+    }
+}
+
+#[cfg(feature = "kaleido")]
+#[allow(deprecated)]
+fn export_images(plot: &Plot, stem: &str, format: PlotFormat) -> Result<(), String> {
+    use plotly::ImageFormat;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    let formats = match format {
+        PlotFormat::Html => vec![],
+        PlotFormat::Png => vec![(ImageFormat::PNG, "png")],
+        PlotFormat::Svg => vec![(ImageFormat::SVG, "svg")],
+        PlotFormat::All => vec![(ImageFormat::PNG, "png"), (ImageFormat::SVG, "svg")],
+    };
+    for (image_format, ext) in formats {
+        let filename = format!("plots/{}.{}", stem, ext);
+        // plotly panics when the kaleido executable can't be found or fails
+        catch_unwind(AssertUnwindSafe(|| {
+            plot.write_image(&filename, image_format, 1200, 1200, 1.0)
+        }))
+        .map_err(|_| "kaleido is not installed or failed to run".to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "kaleido"))]
+fn export_images(_plot: &Plot, _stem: &str, _format: PlotFormat) -> Result<(), String> {
+    Err("built without the `kaleido` feature".to_string())
+}
@@ -0,0 +1,109 @@
+/// Exponents of the success rate and of the speed in the composite score.
+#[derive(Clone, Copy)]
+pub struct ScoreWeights {
+    pub sr: f64,
+    pub time: f64,
+}
+
+impl Default for ScoreWeights {
+    /// Equal weights, making the score the success rate per second of runtime.
+    fn default() -> Self {
+        ScoreWeights { sr: 1.0, time: 1.0 }
+    }
+}
+
+impl ScoreWeights {
+    /// Composite score `success_rate^sr * (1 / runtime_sec)^time`; higher is better.
+    /// Results without a positive, finite runtime, e.g. when every run failed, score 0.
+    pub fn score(&self, success_rate: f64, runtime_sec: f64) -> f64 {
+        if !(runtime_sec.is_finite() && runtime_sec > 0.0) {
+            return 0.0;
+        }
+        success_rate.powf(self.sr) * runtime_sec.powf(-self.time)
+    }
+}
+
+/// Parses `--score-weights`, e.g. `sr=0.7,time=0.3`. Weights left out keep their default.
+pub fn parse_score_weights(s: &str) -> Result<ScoreWeights, String> {
+    let mut weights = ScoreWeights::default();
+    for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let (key, value) = token
+            .split_once('=')
+            .ok_or_else(|| format!("expected <key>=<weight>, got '{}'", token))?;
+        let value: f64 =
+            value.trim().parse().map_err(|e| format!("invalid weight '{}': {}", value, e))?;
+        if !(value.is_finite() && value >= 0.0) {
+            return Err(format!("weight of {} must be non-negative", key.trim()));
+        }
+        match key.trim() {
+            "sr" => weights.sr = value,
+            "time" => weights.time = value,
+            other => return Err(format!("unknown weight '{}', expected sr or time", other)),
+        }
+    }
+    Ok(weights)
+}
+
+/// Score of every function as the geometric mean of its per-dimension scores,
+/// best first. A function with a zero score at any dimension scores 0.
+pub fn leaderboard(functions: Vec<(String, Vec<f64>)>) -> Vec<(String, f64)> {
+    let mut board: Vec<(String, f64)> = functions
+        .into_iter()
+        .filter(|(_, scores)| !scores.is_empty())
+        .map(|(name, scores)| {
+            let log_mean = scores.iter().map(|s| s.ln()).sum::<f64>() / scores.len() as f64;
+            (name, log_mean.exp())
+        })
+        .collect();
+    board.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    board
+}
+
+/// Geometric mean of the positive function scores of a leaderboard, with the number
+/// of functions it covers. Functions scoring 0 never succeeded and are left out.
+pub fn overall_score(board: &[(String, f64)]) -> Option<(f64, usize)> {
+    let positive: Vec<f64> = board.iter().map(|(_, s)| *s).filter(|s| *s > 0.0).collect();
+    if positive.is_empty() {
+        return None;
+    }
+    let log_mean = positive.iter().map(|s| s.ln()).sum::<f64>() / positive.len() as f64;
+    Some((log_mean.exp(), positive.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_weighs_success_rate_against_runtime() {
+        assert_eq!(ScoreWeights::default().score(0.5, 2.0), 0.25);
+        assert_eq!(ScoreWeights { sr: 2.0, time: 0.0 }.score(0.5, 10.0), 0.25);
+        assert_eq!(ScoreWeights { sr: 0.0, time: 0.5 }.score(0.1, 4.0), 0.5);
+    }
+
+    #[test]
+    fn score_is_zero_without_a_usable_runtime() {
+        for runtime in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(ScoreWeights::default().score(1.0, runtime), 0.0, "runtime {}", runtime);
+        }
+    }
+
+    #[test]
+    fn leaderboard_ranks_by_geometric_mean_then_name() {
+        let board = leaderboard(vec![
+            ("Zero".to_string(), vec![0.0, 5.0]),
+            ("Tied".to_string(), vec![2.0]),
+            ("Mean".to_string(), vec![1.0, 4.0]),
+            ("Best".to_string(), vec![3.0]),
+            ("Empty".to_string(), vec![]),
+        ]);
+        let names: Vec<&str> = board.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Best", "Mean", "Tied", "Zero"]);
+        assert!((board[1].1 - 2.0).abs() < 1e-12, "Mean scored {}", board[1].1);
+        assert_eq!(board[3].1, 0.0);
+
+        let (overall, covered) = overall_score(&board).unwrap();
+        assert!((overall - 12f64.cbrt()).abs() < 1e-12, "overall {}", overall);
+        assert_eq!(covered, 3);
+    }
+}
@@ -0,0 +1,249 @@
+/// Result of Welch's unequal-variance two-sample t-test.
+pub struct TTest {
+    pub t: f64,
+    pub df: f64,
+    /// Two-sided p-value
+    pub p_value: f64,
+}
+
+impl TTest {
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+/// Welch's t-test comparing the means of `a` and `b`.
+/// Returns `None` if either sample has fewer than two values or both have zero variance.
+pub fn ttest(a: &[f64], b: &[f64]) -> Option<TTest> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, var_a) = mean_and_variance(a);
+    let (mean_b, var_b) = mean_and_variance(b);
+    let se_a = var_a / a.len() as f64;
+    let se_b = var_b / b.len() as f64;
+    let se = se_a + se_b;
+    if se == 0.0 {
+        return None;
+    }
+
+    let t = (mean_a - mean_b) / se.sqrt();
+    let df = se * se / (se_a * se_a / (a.len() - 1) as f64 + se_b * se_b / (b.len() - 1) as f64);
+    // Two-sided tail probability of Student's t distribution
+    let p_value = incomplete_beta(df / 2.0, 0.5, df / (df + t * t));
+    Some(TTest { t, df, p_value })
+}
+
+/// 95% Wilson score interval `(low, high)` for a success proportion. Unlike the normal
+/// approximation it stays within [0, 1] and does not collapse to a point when all or
+/// none of the trials succeed. Without trials nothing is known, so it spans [0, 1].
+pub fn wilson_interval(successes: usize, trials: usize) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let z = 1.96;
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let denom = 1.0 + z * z / n;
+    let center = (p + z * z / (2.0 * n)) / denom;
+    let half_width = z / denom * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt();
+    ((center - half_width).max(0.0), (center + half_width).min(1.0))
+}
+
+/// Mean of `data` without its `trim_fraction` smallest and largest values, rounded
+/// down to whole values on each side. `trim_fraction` must be below 0.5.
+pub fn trimmed_mean(data: &[f64], trim_fraction: f64) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let cut = (sorted.len() as f64 * trim_fraction).floor() as usize;
+    let kept = &sorted[cut..sorted.len() - cut];
+    kept.iter().sum::<f64>() / kept.len() as f64
+}
+
+/// Number of values outside Tukey's fences, i.e. more than 1.5 interquartile ranges
+/// below the first or above the third quartile.
+pub fn iqr_outliers(data: &[f64]) -> usize {
+    if data.is_empty() {
+        return 0;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let q1 = quantile(&sorted, 0.25);
+    let q3 = quantile(&sorted, 0.75);
+    let fence = 1.5 * (q3 - q1);
+    data.iter().filter(|&&x| x < q1 - fence || x > q3 + fence).count()
+}
+
+// Quantile of sorted, non-empty data, interpolating linearly between ranks
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+// Sample mean and unbiased (n - 1) variance
+fn mean_and_variance(data: &[f64]) -> (f64, f64) {
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+// Lanczos approximation (g = 7, n = 9)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFS[0]
+        + COEFFS[1..].iter().enumerate().map(|(i, c)| c / (x + i as f64 + 1.0)).sum::<f64>();
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Regularized incomplete beta function I_x(a, b).
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges fastest below the mean of the distribution
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+// Modified Lentz evaluation of the incomplete beta continued fraction
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITER: usize = 300;
+    const EPS: f64 = 1e-14;
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        for numerator in [
+            m * (b - m) * x / ((a + m2 - 1.0) * (a + m2)),
+            -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ttest_matches_reference_welch_values() {
+        let a = [
+            27.5, 21.0, 19.0, 23.6, 17.0, 17.9, 16.9, 20.1, 21.9, 22.6, 23.1, 19.6, 19.0, 21.7,
+            21.4,
+        ];
+        let b = [
+            27.1, 22.0, 20.8, 23.4, 23.4, 23.5, 25.8, 22.0, 24.8, 20.2, 21.9, 22.1, 22.9, 20.5,
+            24.4,
+        ];
+        let test = ttest(&a, &b).unwrap();
+        assert!((test.t + 2.455356).abs() < 1e-6, "t = {}", test.t);
+        assert!((test.df - 24.988529).abs() < 1e-6, "df = {}", test.df);
+        assert!((test.p_value - 0.021378).abs() < 1e-6, "p = {}", test.p_value);
+        assert!(test.is_significant(0.05));
+    }
+
+    #[test]
+    fn ttest_needs_two_values_and_some_variance() {
+        assert!(ttest(&[1.0], &[1.0, 2.0]).is_none());
+        assert!(ttest(&[1.0, 1.0], &[2.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn wilson_interval_at_extremes() {
+        let close = |(low, high): (f64, f64), expected: (f64, f64)| {
+            assert!(
+                (low - expected.0).abs() < 1e-6 && (high - expected.1).abs() < 1e-6,
+                "({}, {}), expected {:?}",
+                low,
+                high,
+                expected
+            );
+        };
+        close(wilson_interval(0, 10), (0.0, 0.277540));
+        close(wilson_interval(10, 10), (0.722460, 1.0));
+        // A single trial leaves the rate wide open either way
+        close(wilson_interval(0, 1), (0.0, 0.793457));
+        close(wilson_interval(1, 1), (0.206543, 1.0));
+        assert_eq!(wilson_interval(0, 0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn trimmed_mean_drops_whole_values_from_each_side() {
+        let data = [4.0, 100.0, 2.0, 1.0, 3.0];
+        // 5 * 0.2 = 1 value cut from each end
+        assert_eq!(trimmed_mean(&data, 0.2), 3.0);
+        // 5 * 0.19 rounds down to no trimming at all
+        assert_eq!(trimmed_mean(&data, 0.19), 22.0);
+        assert_eq!(trimmed_mean(&data, 0.0), 22.0);
+    }
+
+    #[test]
+    fn iqr_outliers_excludes_values_on_the_fences() {
+        // Q1 = 3, Q3 = 7, so the fences sit at 3 - 6 = -3 and 7 + 6 = 13
+        let mut data = [-3.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 13.0];
+        assert_eq!(iqr_outliers(&data), 0);
+        data[8] = 13.5;
+        assert_eq!(iqr_outliers(&data), 1);
+        data[0] = -3.5;
+        assert_eq!(iqr_outliers(&data), 2);
+        assert_eq!(iqr_outliers(&[]), 0);
+    }
+
+    #[test]
+    fn incomplete_beta_matches_closed_forms() {
+        assert!((incomplete_beta(1.0, 1.0, 0.3) - 0.3).abs() < 1e-12);
+        assert!((incomplete_beta(2.5, 2.5, 0.5) - 0.5).abs() < 1e-12);
+        // I_x(2, 3) = 12 (x^2/2 - 2x^3/3 + x^4/4)
+        assert!((incomplete_beta(2.0, 3.0, 0.3) - 0.3483).abs() < 1e-12);
+        assert_eq!(incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(incomplete_beta(2.0, 3.0, 1.0), 1.0);
+    }
+}
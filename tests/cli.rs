@@ -0,0 +1,37 @@
+//! End-to-end checks of the benchmark binary, run in a scratch directory.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Runs a tiny Sphere benchmark in `dir` with the extra `args`.
+fn run_benchmark(dir: &Path, args: &[&str]) {
+    std::fs::create_dir_all(dir).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_globalsearch-benches"))
+        .current_dir(dir)
+        .args(["--function", "sphere", "--dim", "2", "--runs", "2"])
+        .args(["--population-size", "20", "--iterations", "2", "--save-json", "stats.json"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn no_plots_writes_no_plot_files() {
+    let dir = scratch_dir("no-plots");
+    run_benchmark(&dir, &["--no-plots"]);
+    assert!(dir.join("stats.json").exists());
+    assert!(!dir.join("plots").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let dir = scratch_dir("with-plots");
+    run_benchmark(&dir, &[]);
+    assert!(dir.join("plots").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}